@@ -0,0 +1,105 @@
+//! Timing the same piece of work over and over, for `aoc bench`.
+//!
+//! A single run is at the mercy of whatever else the machine is doing, so each stage is run a
+//! few times untimed to warm up caches, then timed repeatedly and summarised.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Summary of the time taken by a number of runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single run.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise the times of some runs. There must be at least one.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Run `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// One line of a benchmark report: the timings of a stage of a day.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub day: usize,
+    /// `parse`, `1` or `2`.
+    pub stage: String,
+    pub stats: Stats,
+}
+
+/// The report as JSON, with times in nanoseconds, so that runs can be compared by other tools.
+pub fn to_json(warmup: usize, entries: &[Entry]) -> String {
+    let mut json = String::new();
+    writeln!(json, "{{").unwrap();
+    writeln!(json, "  \"warmup\": {},", warmup).unwrap();
+    writeln!(json, "  \"results\": [").unwrap();
+    for (i, entry) in entries.iter().enumerate() {
+        let stats = &entry.stats;
+        write!(
+            json,
+            "    {{\"day\": {}, \"stage\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \
+             \"median_ns\": {}, \"mean_ns\": {}, \"stddev_ns\": {}}}",
+            entry.day,
+            entry.stage,
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        )
+        .unwrap();
+        json.push_str(if i + 1 < entries.len() { ",\n" } else { "\n" });
+    }
+    writeln!(json, "  ]").unwrap();
+    writeln!(json, "}}").unwrap();
+    json
+}
@@ -0,0 +1,1064 @@
+use aoc::{
+    bench::{self, Entry, Stats},
+    days::{self, Day, Parsed},
+    error, info,
+    ledger::{self, Guess, Ledger, Verdict},
+    memory::{self, Usage},
+    params::{self, Param},
+    repl,
+    solution::{Answer, Part},
+    stepper,
+    util::{
+        bigint,
+        input::{self, Source},
+        json, letters,
+        log::{self, Level},
+        par,
+        render::Format,
+    },
+};
+use std::{
+    env, fmt, fs,
+    io::{self, IsTerminal, Write},
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+const USAGE: &str = "\
+Usage: aoc [run] [--day N]... [--part 1|2|all] [--input PATH] [--format text|json]
+                [--threads N] [--exact] [--set KEY=VALUE]... [-v|-q]...
+       aoc bench [--day N]... [--part 1|2|all] [--input PATH] [--threads N]
+                 [--exact] [--set KEY=VALUE]... [-v|-q]... [--warmup N]
+                 [--iterations N] [--json PATH]
+       aoc render --day N [--input PATH] [--format text|ansi|ppm|svg]
+                  [--scale N] [--output PATH] [-v|-q]...
+       aoc step --day N [--input PATH] [-v|-q]...
+       aoc repl --day N [--input PATH] [--exact] [--set KEY=VALUE]... [-v|-q]...
+       aoc answer record --day N --part 1|2 --value VALUE
+                         --verdict correct|wrong|too_low|too_high
+       aoc answer list [--day N]...
+       aoc params [--day N]...
+       aoc list
+
+Runs the solvers for the given days, or every day in sequence if no day is
+given. --format json prints a JSON object per line for each day and part
+instead, with the answer, the input, the times taken to parse and solve in
+nanoseconds and any error. Answers drawn in letters also have the rows drawn, as
+lines, and the answer is null if the letters can't be read. Built with the
+count-allocs feature, run also reports how many allocations each stage made, the
+bytes they came to and the most bytes live at once.
+
+bench runs each stage (parse, part 1, part 2) --warmup times untimed (default
+1), then --iterations times timed (default 10), and reports the min, median,
+mean and standard deviation. --json also writes the report to PATH.
+
+render draws a picture of a day's puzzle, for the days that have one, to
+stdout or to --output PATH. The format defaults to the one PATH's extension
+suggests, or text; images draw each cell as a --scale pixel square (default 4).
+
+step runs a day's simulation a step at a time, for the days that are one,
+reading commands from stdin: n and b step forward and back, g N goes to step N,
+p prints the state, and w NAME OP VALUE (like w units >= 20) stops stepping
+forward once a condition holds. h lists every command.
+
+repl parses a day's input once and reads commands from stdin to look into it: 1
+and 2 solve a part again, run solves both, show prints the puzzle's picture and
+history, !N and !! run earlier commands again. Some days have commands of their
+own, like size PATH on day 7, row Y on day 15 and eval NAME on day 21, which h
+lists along with the rest.
+
+answer record keeps what the puzzle site said about an answer in a ledger,
+answers.toml in this crate or $AOC_LEDGER if it is set, and answer list shows
+what has been kept. Answers run computes are checked against the ledger, and any
+that a confirmed answer or a too high or too low hint rules out are reported
+and count as failures.
+
+Inputs are read from src/data/dayN_1.txt in this crate, or from dayN_1.txt in
+$AOC_INPUT_DIR if it is set. --input reads a single day's input from PATH
+instead, where - means stdin.
+
+Days with independent pieces of work share them out between as many threads as
+there are CPUs, or $AOC_THREADS if it is set; --threads (-j) sets the number
+instead. The answers are the same however many there are.
+
+Some days are stated in terms of numbers that the examples in the puzzles
+change, like the row day 15 looks at. These are parameters with the real
+puzzle's values as defaults, which aoc params lists along with what they are set
+to. aoc.toml in this crate, or $AOC_CONFIG if it is set, can set them in a table
+for each day, like [day15] then probe_row = 10 on the next line, and any command
+can take --set day15.probe_row=10 to set one for that run.
+
+Days 11, 21 and 25 can do their sums with integers of any size instead of
+machine integers, which is slower but never overflows. --exact turns this on,
+as does setting $AOC_EXACT to anything but 0.
+
+Answers go to stdout and diagnostics to stderr. Diagnostics are logged at
+error, info, debug or trace level, starting from info or $AOC_LOG if it is
+set; each -v (--verbose) shows one more level and each -q (--quiet) one less.";
+
+enum Command {
+    List,
+    Run {
+        days: Vec<usize>,
+        parts: Vec<Part>,
+        input: Option<Source>,
+        report: Report,
+    },
+    Bench {
+        days: Vec<usize>,
+        parts: Vec<Part>,
+        input: Option<Source>,
+        warmup: usize,
+        iterations: usize,
+        json: Option<PathBuf>,
+    },
+    Render {
+        day: usize,
+        input: Option<Source>,
+        format: Format,
+        scale: usize,
+        output: Option<PathBuf>,
+    },
+    Step {
+        day: usize,
+        input: Option<Source>,
+    },
+    Repl {
+        day: usize,
+        input: Option<Source>,
+    },
+    Record(Guess),
+    Answers {
+        days: Vec<usize>,
+    },
+    Params {
+        days: Vec<usize>,
+    },
+}
+
+/// Which command the arguments so far are for, before its options are all known.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Render,
+    Step,
+    Repl,
+    Record,
+    Answers,
+    Params,
+}
+
+/// How `aoc run` reports the answers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Report {
+    /// For people: the answers as they come, then a table of times.
+    Text,
+    /// For scripts: a line of JSON for each day and part.
+    Json,
+}
+
+/// Parse the value following `flag` as a count.
+fn count(flag: &str, value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("'{}' is not a number of times", value))
+}
+
+/// Settings that apply whatever the command.
+struct Global {
+    level: Level,
+    /// Threads for the days that use them, if not the default.
+    threads: Option<usize>,
+    /// Whether to do sums exactly, if asked to on the command line.
+    exact: bool,
+    /// Parameters set on the command line, in order.
+    params: Vec<(&'static Param, i64)>,
+}
+
+/// Parse the command line, returning the command along with the global settings it asks for.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(Command, Global), String> {
+    let mut level = log::level();
+    let mut threads = None;
+    let mut exact = false;
+    let mut params = vec![];
+    let mut mode = Mode::Run;
+    let mut days = vec![];
+    let mut parts = vec![Part::One, Part::Two];
+    let mut input = None;
+    let mut report = Report::Text;
+    let mut warmup = None;
+    let mut iterations = None;
+    let mut json = None;
+    let mut format = None;
+    let mut scale = None;
+    let mut output = None;
+    let mut value = None;
+    let mut verdict = None;
+    let mut first = true;
+    while let Some(arg) = args.next() {
+        if let Some(adjusted) = level.after_flag(&arg) {
+            level = adjusted;
+            first = false;
+            continue;
+        }
+        match arg.as_str() {
+            "list" if first => {
+                let global = Global {
+                    level,
+                    threads,
+                    exact,
+                    params,
+                };
+                return Ok((Command::List, global));
+            }
+            "run" if first => {}
+            "bench" if first => mode = Mode::Bench,
+            "render" if first => mode = Mode::Render,
+            "step" if first => mode = Mode::Step,
+            "params" if first => mode = Mode::Params,
+            "repl" if first => mode = Mode::Repl,
+            "answer" if first => {
+                mode = match args.next().as_deref() {
+                    Some("record") => Mode::Record,
+                    Some("list") => Mode::Answers,
+                    _ => return Err(String::from("answer needs record or list")),
+                }
+            }
+            "--day" | "-d" => {
+                let value = args.next().ok_or("--day needs a value")?;
+                let number = value
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a day number", value))?;
+                if days::get(number).is_none() {
+                    return Err(format!("day {} has not been solved", number));
+                }
+                days.push(number);
+            }
+            "--part" | "-p" => {
+                let value = args.next().ok_or("--part needs a value")?;
+                parts = match value.as_str() {
+                    "1" => vec![Part::One],
+                    "2" => vec![Part::Two],
+                    "all" => vec![Part::One, Part::Two],
+                    _ => return Err(format!("'{}' is not a part, expected 1, 2 or all", value)),
+                };
+            }
+            "--input" | "-i" => {
+                let value = args.next().ok_or("--input needs a path, or - for stdin")?;
+                input = Some(Source::from_arg(&value));
+            }
+            "--warmup" | "-w" if mode == Mode::Bench => warmup = Some(count(&arg, args.next())?),
+            "--iterations" | "-n" if mode == Mode::Bench => {
+                iterations = Some(count(&arg, args.next())?)
+            }
+            "--threads" | "-j" => {
+                let value = args.next().ok_or("--threads needs a value")?;
+                threads = match value.parse() {
+                    Ok(0) | Err(_) => {
+                        return Err(format!("'{}' is not a number of threads", value))
+                    }
+                    Ok(n) => Some(n),
+                };
+            }
+            "--exact" => exact = true,
+            "--set" => {
+                let value = args.next().ok_or("--set needs KEY=VALUE")?;
+                params.push(params::parse_assignment(&value)?);
+            }
+            "--json" if mode == Mode::Bench => {
+                let value = args.next().ok_or("--json needs a path")?;
+                json = Some(PathBuf::from(value));
+            }
+            "--format" | "-f" if mode == Mode::Run => {
+                let value = args.next().ok_or("--format needs a value")?;
+                report = match value.as_str() {
+                    "text" => Report::Text,
+                    "json" => Report::Json,
+                    _ => {
+                        return Err(format!(
+                            "'{}' is not an answer format, expected text or json",
+                            value
+                        ))
+                    }
+                };
+            }
+            "--format" | "-f" if mode == Mode::Render => {
+                let value = args.next().ok_or("--format needs a value")?;
+                format = Some(value.parse::<Format>()?);
+            }
+            "--scale" | "-s" if mode == Mode::Render => scale = Some(count(&arg, args.next())?),
+            "--output" | "-o" if mode == Mode::Render => {
+                let value = args.next().ok_or("--output needs a path")?;
+                output = Some(PathBuf::from(value));
+            }
+            "--value" if mode == Mode::Record => {
+                value = Some(args.next().ok_or("--value needs a value")?);
+            }
+            "--verdict" if mode == Mode::Record => {
+                let value = args.next().ok_or("--verdict needs a value")?;
+                verdict = Some(value.parse::<Verdict>()?);
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+        first = false;
+    }
+    if input.is_some() && days.len() != 1 {
+        return Err(String::from("--input needs a single --day to go with it"));
+    }
+    if iterations == Some(0) {
+        return Err(String::from("--iterations must be at least 1"));
+    }
+    let command = match mode {
+        Mode::Run => Command::Run {
+            days,
+            parts,
+            input,
+            report,
+        },
+        Mode::Bench => Command::Bench {
+            days,
+            parts,
+            input,
+            warmup: warmup.unwrap_or(1),
+            iterations: iterations.unwrap_or(10),
+            json,
+        },
+        Mode::Render => {
+            let [day] = days[..] else {
+                return Err(String::from("render needs a single --day"));
+            };
+            let format = format
+                .or_else(|| {
+                    let extension = output.as_ref()?.extension()?.to_str()?;
+                    Format::from_extension(extension)
+                })
+                .unwrap_or(Format::Text);
+            Command::Render {
+                day,
+                input,
+                format,
+                scale: scale.unwrap_or(4),
+                output,
+            }
+        }
+        Mode::Step => {
+            let [day] = days[..] else {
+                return Err(String::from("step needs a single --day"));
+            };
+            if input == Some(Source::Stdin) {
+                return Err(String::from(
+                    "step reads commands from stdin, so can't read input from it",
+                ));
+            }
+            Command::Step { day, input }
+        }
+        Mode::Repl => {
+            let [day] = days[..] else {
+                return Err(String::from("repl needs a single --day"));
+            };
+            if input == Some(Source::Stdin) {
+                return Err(String::from(
+                    "repl reads commands from stdin, so can't read input from it",
+                ));
+            }
+            Command::Repl { day, input }
+        }
+        Mode::Record => {
+            let ([day], [part]) = (&days[..], &parts[..]) else {
+                return Err(String::from(
+                    "answer record needs a single --day and --part",
+                ));
+            };
+            Command::Record(Guess {
+                day: *day,
+                part: *part,
+                value: value.ok_or("answer record needs a --value")?,
+                verdict: verdict.ok_or("answer record needs a --verdict")?,
+            })
+        }
+        Mode::Answers => Command::Answers { days },
+        Mode::Params => Command::Params { days },
+    };
+    let global = Global {
+        level,
+        threads,
+        exact,
+        params,
+    };
+    Ok((command, global))
+}
+
+/// A timed step of solving a day.
+#[derive(Clone, Copy)]
+enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+/// How running a single stage went.
+enum Outcome {
+    Solved(Duration),
+    /// Failed with an error, which has already been logged.
+    Failed(Duration, String),
+    Panicked(Duration),
+    /// Gave an answer that the ledger rules out, for these reasons.
+    Disputed(Duration, Vec<String>),
+}
+
+impl Outcome {
+    fn elapsed(&self) -> Duration {
+        match self {
+            Outcome::Solved(elapsed)
+            | Outcome::Failed(elapsed, _)
+            | Outcome::Panicked(elapsed)
+            | Outcome::Disputed(elapsed, _) => *elapsed,
+        }
+    }
+
+    fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Solved(_) => None,
+            Outcome::Failed(_, err) => Some(err.clone()),
+            Outcome::Panicked(_) => Some(String::from("panicked")),
+            Outcome::Disputed(_, reasons) => {
+                Some(format!("the ledger rules this out: {}", reasons.join(", ")))
+            }
+        }
+    }
+}
+
+/// Time `f`, catching any panic so that one broken day doesn't stop the rest.
+fn timed<T>(f: impl FnOnce() -> T) -> (Duration, Option<T>) {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    (start.elapsed(), result.ok())
+}
+
+fn print_answer(part: Part, answer: &Answer) {
+    match answer {
+        Answer::Line(line) => println!("Part {}: {}", part, line),
+        Answer::Lines(lines) => {
+            println!("Part {}:", part);
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+/// How running each stage of a day went, what it allocated, and the answers it gave.
+#[derive(Default)]
+struct DayRun {
+    results: Vec<(Stage, Outcome, Usage)>,
+    answers: Vec<(Part, Answer)>,
+}
+
+/// Print a line of JSON for each of `parts` that `day` has, from how running it went. If the
+/// input couldn't be parsed, every one of `parts` gets the error.
+fn print_json(day: &Day, parts: &[Part], run: &DayRun) {
+    let parse = run
+        .results
+        .iter()
+        .find(|(stage, ..)| matches!(stage, Stage::Parse))
+        .map(|(_, outcome, _)| outcome);
+    for &part in parts {
+        let solved = run
+            .results
+            .iter()
+            .find(|(stage, ..)| matches!(stage, Stage::Solve(solved) if *solved == part));
+        let (elapsed, usage, error) = match (parse, solved) {
+            (_, Some((_, outcome, usage))) => {
+                (Some(outcome.elapsed()), Some(usage), outcome.error())
+            }
+            (Some(parse @ (Outcome::Failed(..) | Outcome::Panicked(_))), None) => (
+                None,
+                None,
+                parse.error().map(|err| format!("parse: {}", err)),
+            ),
+            // the day has no such part
+            _ => continue,
+        };
+        let answer = match run.answers.iter().find(|(answered, _)| *answered == part) {
+            Some((_, Answer::Line(line))) => json::string(line),
+            Some((_, Answer::Lines(lines))) => format!(
+                "{}, \"lines\": {}",
+                json::optional(letters::read(lines).as_deref()),
+                json::strings(lines)
+            ),
+            None => String::from("null"),
+        };
+        let nanos = |elapsed: Option<Duration>| {
+            elapsed.map_or(String::from("null"), |elapsed| {
+                elapsed.as_nanos().to_string()
+            })
+        };
+        // only with the counts to back them up
+        let memory = match usage {
+            Some(usage) if memory::ENABLED => format!(
+                "\"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}, ",
+                usage.allocations, usage.bytes, usage.peak
+            ),
+            _ => String::new(),
+        };
+        println!(
+            "{{\"day\": {}, \"part\": {}, \"input\": {}, \"parse_ns\": {}, \"elapsed_ns\": {}, \
+             {}\"answer\": {}, \"error\": {}}}",
+            day.number,
+            part,
+            json::string(&input::describe(day.number)),
+            nanos(parse.map(Outcome::elapsed)),
+            nanos(elapsed),
+            memory,
+            answer,
+            json::optional(error.as_deref())
+        );
+    }
+}
+
+/// Parse a day's input and solve the requested parts, checking the answers against `ledger`. In
+/// text, the answers are printed as they come; they are also returned, for the JSON report.
+fn run_day(day: &Day, parts: &[Part], report: Report, ledger: &Ledger) -> DayRun {
+    if report == Report::Text {
+        println!(
+            "--- Day {}: {} ({}) ---",
+            day.number,
+            day.title,
+            input::describe(day.number)
+        );
+    }
+    let mut run = DayRun::default();
+    let text = match input::read(day.number) {
+        Ok(text) => text,
+        Err(err) => {
+            error!("{}", err);
+            run.results.push((
+                Stage::Parse,
+                Outcome::Failed(Duration::ZERO, err.to_string()),
+                Usage::default(),
+            ));
+            return run;
+        }
+    };
+
+    let (parse, usage) = memory::measure(|| timed(|| day.parse_input(&text)));
+    let parsed = match parse {
+        (elapsed, Some(Ok(parsed))) => {
+            run.results
+                .push((Stage::Parse, Outcome::Solved(elapsed), usage));
+            parsed
+        }
+        (elapsed, Some(Err(err))) => {
+            error!("{}", err);
+            let outcome = Outcome::Failed(elapsed, err.to_string());
+            run.results.push((Stage::Parse, outcome, usage));
+            return run;
+        }
+        (elapsed, None) => {
+            run.results
+                .push((Stage::Parse, Outcome::Panicked(elapsed), usage));
+            return run;
+        }
+    };
+
+    for &part in parts {
+        let (solve, usage) = memory::measure(|| timed(|| parsed.solve(part)));
+        match solve {
+            (elapsed, Some(Some(answer))) => {
+                if report == Report::Text {
+                    print_answer(part, &answer);
+                }
+                let reasons: Vec<String> = ledger::answer_value(&answer).map_or(vec![], |value| {
+                    let guesses = ledger.contradictions(day.number, part, &value);
+                    guesses.iter().map(ToString::to_string).collect()
+                });
+                let outcome = if reasons.is_empty() {
+                    Outcome::Solved(elapsed)
+                } else {
+                    error!(
+                        "day {} part {}: the ledger rules this answer out: {}",
+                        day.number,
+                        part,
+                        reasons.join(", ")
+                    );
+                    Outcome::Disputed(elapsed, reasons)
+                };
+                run.answers.push((part, answer));
+                run.results.push((Stage::Solve(part), outcome, usage));
+            }
+            (_, Some(None)) => {}
+            (elapsed, None) => {
+                run.results
+                    .push((Stage::Solve(part), Outcome::Panicked(elapsed), usage))
+            }
+        }
+    }
+    run
+}
+
+/// Print a table of how every stage went, with what it allocated if that was counted.
+fn print_summary(results: &[(&Day, Stage, Outcome, Usage)]) {
+    println!();
+    let memory_header = if memory::ENABLED {
+        format!("{:>8}  {:>11}  {:>11}  ", "Allocs", "Allocated", "Peak")
+    } else {
+        String::new()
+    };
+    println!(
+        "{:>3}  {:>5}  {:>12}  {}Status",
+        "Day", "Stage", "Time", memory_header
+    );
+    for (day, stage, outcome, usage) in results {
+        let status = match outcome {
+            Outcome::Solved(_) => "ok",
+            Outcome::Failed(..) => "failed",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::Disputed(..) => "disputed",
+        };
+        let memory = if memory::ENABLED {
+            format!(
+                "{:>8}  {:>11}  {:>11}  ",
+                usage.allocations,
+                memory::format_bytes(usage.bytes),
+                memory::format_bytes(usage.peak)
+            )
+        } else {
+            String::new()
+        };
+        println!(
+            "{:>3}  {:>5}  {:>12}  {}{}",
+            day.number,
+            stage.to_string(),
+            format!("{:.2?}", outcome.elapsed()),
+            memory,
+            status
+        );
+    }
+    let total: Duration = results
+        .iter()
+        .map(|(_, _, outcome, _)| outcome.elapsed())
+        .sum();
+    println!("{:>3}  {:>5}  {:>12}", "", "", format!("{:.2?}", total));
+}
+
+fn list() {
+    for day in days::DAYS {
+        println!("{:>2}  {}", day.number, day.title);
+    }
+}
+
+/// The days numbered in `numbers`, or every day if there are none.
+fn select(numbers: &[usize]) -> Vec<&'static Day> {
+    if numbers.is_empty() {
+        days::DAYS.iter().collect()
+    } else {
+        numbers
+            .iter()
+            .filter_map(|&number| days::get(number))
+            .collect()
+    }
+}
+
+fn run(numbers: &[usize], parts: &[Part], report: Report) -> bool {
+    let selected = select(numbers);
+    let ledger = match Ledger::load(&ledger::default_path()) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            error!("{}", err);
+            return false;
+        }
+    };
+
+    let mut results = vec![];
+    for day in selected {
+        let day_run = run_day(day, parts, report, &ledger);
+        if report == Report::Json {
+            print_json(day, parts, &day_run);
+        }
+        for (stage, outcome, usage) in day_run.results {
+            results.push((day, stage, outcome, usage));
+        }
+    }
+    if report == Report::Text {
+        print_summary(&results);
+    }
+    results
+        .iter()
+        .all(|(_, _, outcome, _)| matches!(outcome, Outcome::Solved(_)))
+}
+
+/// Time every stage of a day, returning whatever could be timed and whether every stage worked.
+/// Stages that fail or panic are reported and skipped, along with any stages that depend on them.
+fn bench_day(day: &Day, parts: &[Part], warmup: usize, iterations: usize) -> (Vec<Entry>, bool) {
+    let mut entries = vec![];
+    let text = match input::read(day.number) {
+        Ok(text) => text,
+        Err(err) => {
+            error!("day {}: {}", day.number, err);
+            return (entries, false);
+        }
+    };
+    let entry = |stage: Stage, stats: Stats| Entry {
+        day: day.number,
+        stage: stage.to_string(),
+        stats,
+    };
+
+    // run each stage once before timing it, to make sure that it works
+    let parsed = match timed(|| day.parse_input(&text)) {
+        (_, Some(Ok(parsed))) => parsed,
+        (_, Some(Err(err))) => {
+            error!("{}", err);
+            return (entries, false);
+        }
+        (_, None) => return (entries, false),
+    };
+    let stats = bench::measure(warmup, iterations, || day.parse_input(&text));
+    entries.push(entry(Stage::Parse, stats));
+
+    let mut ok = true;
+    for &part in parts {
+        match timed(|| parsed.solve(part)) {
+            (_, Some(Some(_))) => {
+                let stats = bench::measure(warmup, iterations, || parsed.solve(part));
+                entries.push(entry(Stage::Solve(part), stats));
+            }
+            (_, Some(None)) => {}
+            (_, None) => ok = false,
+        }
+    }
+    (entries, ok)
+}
+
+fn print_bench(entries: &[Entry]) {
+    println!();
+    println!(
+        "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>5}",
+        "Day", "Stage", "Min", "Median", "Mean", "Stddev", "Runs"
+    );
+    for entry in entries {
+        let stats = &entry.stats;
+        println!(
+            "{:>3}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>5}",
+            entry.day,
+            entry.stage,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.stddev),
+            stats.iterations
+        );
+    }
+}
+
+fn bench(
+    numbers: &[usize],
+    parts: &[Part],
+    warmup: usize,
+    iterations: usize,
+    json: Option<PathBuf>,
+) -> bool {
+    let selected = select(numbers);
+    let mut entries = vec![];
+    let mut ok = true;
+    for day in &selected {
+        info!("Benchmarking day {}", day.number);
+        let (timed, day_ok) = bench_day(day, parts, warmup, iterations);
+        entries.extend(timed);
+        ok &= day_ok;
+    }
+    print_bench(&entries);
+    if let Some(path) = json {
+        if let Err(err) = fs::write(&path, bench::to_json(warmup, &entries)) {
+            error!("writing {}: {}", path.display(), err);
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Add `guess` to the ledger.
+fn record(guess: Guess) -> bool {
+    let path = ledger::default_path();
+    let mut ledger = match Ledger::load(&path) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            error!("{}", err);
+            return false;
+        }
+    };
+    if guess.verdict == Verdict::Correct {
+        // the hints recorded before should agree with the answer, apart from whatever was said
+        // about this same value, which the answer overrides
+        for other in ledger.contradictions(guess.day, guess.part, &guess.value) {
+            if other.value != guess.value {
+                error!("{} disagrees with {}", guess.value, other);
+            }
+        }
+    }
+    let message = format!(
+        "Recorded day {} part {}: {} is {}",
+        guess.day, guess.part, guess.value, guess.verdict
+    );
+    if let Err(message) = ledger.record(guess) {
+        error!("{}", message);
+        return false;
+    }
+    if let Err(err) = ledger.save(&path) {
+        error!("{}", err);
+        return false;
+    }
+    info!("{}", message);
+    true
+}
+
+/// Print the guesses recorded for the days numbered in `numbers`, or every day if there are none.
+fn answers(numbers: &[usize]) -> bool {
+    let ledger = match Ledger::load(&ledger::default_path()) {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            error!("{}", err);
+            return false;
+        }
+    };
+    println!("{:>3}  {:>4}  {:<8}  Value", "Day", "Part", "Verdict");
+    for guess in &ledger.guesses {
+        if numbers.is_empty() || numbers.contains(&guess.day) {
+            println!(
+                "{:>3}  {:>4}  {:<8}  {}",
+                guess.day,
+                guess.part.to_string(),
+                guess.verdict.to_string(),
+                guess.value
+            );
+        }
+    }
+    true
+}
+
+/// Print the parameters of the days numbered in `numbers`, or every day if there are none.
+fn list_params(numbers: &[usize]) {
+    println!(
+        "{:<24}  {:>14}  {:>14}  Description",
+        "Parameter", "Value", "Default"
+    );
+    for param in params::all() {
+        if numbers.is_empty() || numbers.contains(&param.day) {
+            println!(
+                "{:<24}  {:>14}  {:>14}  {}",
+                param.to_string(),
+                param.value(),
+                param.default,
+                param.help
+            );
+        }
+    }
+}
+
+/// Read and parse the input for day `number`, reporting why if that fails.
+fn parse_day(number: usize) -> Option<Box<dyn Parsed>> {
+    let day = days::get(number).expect("day numbers are checked when parsing arguments");
+    let text = match input::read(day.number) {
+        Ok(text) => text,
+        Err(err) => {
+            error!("day {}: {}", day.number, err);
+            return None;
+        }
+    };
+    match day.parse_input(&text) {
+        Ok(parsed) => Some(parsed),
+        Err(err) => {
+            error!("{}", err);
+            None
+        }
+    }
+}
+
+/// Draw a day's puzzle in `format`, writing it to `output` or stdout.
+fn render(number: usize, format: Format, scale: usize, output: Option<PathBuf>) -> bool {
+    let Some(parsed) = parse_day(number) else {
+        return false;
+    };
+    let Some(picture) = parsed.render() else {
+        error!("day {} has nothing to draw", number);
+        return false;
+    };
+    let bytes = picture.encode(format, scale);
+    let written = match &output {
+        Some(path) => fs::write(path, bytes),
+        None => io::stdout().write_all(&bytes),
+    };
+    if let Err(err) = written {
+        let target = output.map_or(String::from("stdout"), |path| path.display().to_string());
+        error!("writing {}: {}", target, err);
+        return false;
+    }
+    true
+}
+
+/// Step through a day's simulation, taking commands from stdin.
+fn step(number: usize) -> bool {
+    let Some(parsed) = parse_day(number) else {
+        return false;
+    };
+    let Some(mut stepper) = parsed.simulate() else {
+        error!("day {} has no simulation to step through", number);
+        return false;
+    };
+    let interactive = io::stdin().is_terminal();
+    let ansi = io::stdout().is_terminal();
+    let result = stepper::session(
+        stepper.as_mut(),
+        io::stdin().lock(),
+        io::stdout().lock(),
+        interactive,
+        ansi,
+    );
+    if let Err(err) = result {
+        error!("{}", err);
+        return false;
+    }
+    true
+}
+
+/// Look into a day's parsed input, taking commands from stdin.
+fn repl(number: usize) -> bool {
+    let Some(parsed) = parse_day(number) else {
+        return false;
+    };
+    let interactive = io::stdin().is_terminal();
+    let ansi = io::stdout().is_terminal();
+    let result = repl::session(
+        parsed.as_ref(),
+        io::stdin().lock(),
+        io::stdout().lock(),
+        interactive,
+        ansi,
+    );
+    if let Err(err) = result {
+        error!("{}", err);
+        return false;
+    }
+    true
+}
+
+fn main() {
+    // the flags move the level from wherever AOC_LOG starts it, which has to be settled before
+    // anything wrong with AOC_LOG can be reported
+    match log::env_level() {
+        Ok(level) => log::set_level(level),
+        Err(message) => {
+            log::set_level(Level::Info);
+            error!("{}", message);
+        }
+    }
+    let command = match parse_args(env::args().skip(1)) {
+        Ok((command, global)) => {
+            log::set_level(global.level);
+            if let Some(threads) = global.threads {
+                par::set_threads(threads);
+            }
+            if global.exact {
+                bigint::set_exact(true);
+            }
+            // the command line wins over the config file
+            if let Err(err) = params::load(&params::default_path()) {
+                error!("{}", err);
+                process::exit(1);
+            }
+            for (param, value) in global.params {
+                params::set(param, value);
+            }
+            command
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::List => list(),
+        Command::Run {
+            days,
+            parts,
+            input,
+            report,
+        } => {
+            if let Some(source) = input {
+                input::set_override(source).expect("input source is only set once");
+            }
+            if !run(&days, &parts, report) {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            parts,
+            input,
+            warmup,
+            iterations,
+            json,
+        } => {
+            if let Some(source) = input {
+                input::set_override(source).expect("input source is only set once");
+            }
+            if !bench(&days, &parts, warmup, iterations, json) {
+                process::exit(1);
+            }
+        }
+        Command::Render {
+            day,
+            input,
+            format,
+            scale,
+            output,
+        } => {
+            if let Some(source) = input {
+                input::set_override(source).expect("input source is only set once");
+            }
+            if !render(day, format, scale, output) {
+                process::exit(1);
+            }
+        }
+        Command::Record(guess) => {
+            if !record(guess) {
+                process::exit(1);
+            }
+        }
+        Command::Answers { days } => {
+            if !answers(&days) {
+                process::exit(1);
+            }
+        }
+        Command::Params { days } => list_params(&days),
+        Command::Step { day, input } => {
+            if let Some(source) = input {
+                input::set_override(source).expect("input source is only set once");
+            }
+            if !step(day) {
+                process::exit(1);
+            }
+        }
+        Command::Repl { day, input } => {
+            if let Some(source) = input {
+                input::set_override(source).expect("input source is only set once");
+            }
+            if !repl(day) {
+                process::exit(1);
+            }
+        }
+    }
+}
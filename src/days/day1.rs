@@ -0,0 +1,66 @@
+use crate::error::parse_at;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Total calories carried by each elf.
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        input::records(input)
+            .iter()
+            .map(|elf| {
+                elf.iter()
+                    .map(|x| parse_at::<usize>(input, x, "a calorie count"))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn part1(calories: &Vec<usize>) -> Answer {
+        part1(calories).into()
+    }
+
+    fn part2(calories: &Vec<usize>) -> Option<Answer> {
+        Some(part2(calories).into())
+    }
+}
+
+/// The most calories carried by one elf.
+pub fn part1(calories: &[usize]) -> usize {
+    find_max(calories)
+}
+
+/// The calories carried by the three elves carrying the most.
+pub fn part2(calories: &[usize]) -> usize {
+    let topthreecal = find_top_three(calories);
+    topthreecal.iter().sum()
+}
+
+fn find_max(calories: &[usize]) -> usize {
+    let mut max = 0;
+
+    for cal in calories {
+        if *cal > max {
+            max = *cal;
+        }
+    }
+    max
+}
+
+fn find_top_three(calories: &[usize]) -> Vec<usize> {
+    let mut top_three_vals = vec![0, 0, 0];
+    let mut smallest_top_three = 0;
+
+    for cal in calories {
+        if *cal > smallest_top_three {
+            top_three_vals.push(*cal);
+            top_three_vals.sort_by(|a, b| b.cmp(a));
+            top_three_vals.pop();
+            smallest_top_three = top_three_vals[2];
+        }
+    }
+    top_three_vals
+}
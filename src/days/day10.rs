@@ -0,0 +1,110 @@
+use crate::debug;
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+
+pub enum Command {
+    Noop,
+    Addx(i32),
+}
+
+// Increment the cycle counter. If we're at an output cycle return a value.
+// Also draws the pixel the CRT is currently scanning onto `screen`.
+fn advance_cycle(cycle: &mut usize, register: &i32, screen: &mut Vec<String>) -> Option<i32> {
+    if cycle.is_multiple_of(40) {
+        screen.push(String::new());
+    }
+    let row = screen.last_mut().unwrap();
+    if (register - (*cycle as i32 % 40)).abs() <= 1 {
+        row.push('#');
+    } else {
+        row.push('.');
+    }
+    *cycle += 1;
+    if (*cycle >= 20) && (*cycle - 20).is_multiple_of(40) {
+        return Some(*register);
+    }
+    None
+}
+
+fn read_commands(input: &str) -> Result<Vec<Command>> {
+    let lineiter = input::lines(input);
+    // Read input into a command buffer
+    let mut commands: Vec<Command> = vec![];
+    for line in lineiter {
+        let cmd = match line.split_once(' ') {
+            None if line == "noop" => Command::Noop,
+            Some(("addx", val)) => Command::Addx(parse_at(input, val, "an integer")?),
+            _ => return Err(AocError::at(input, line, "`noop` or `addx V`")),
+        };
+        commands.push(cmd);
+    }
+    Ok(commands)
+}
+
+/// Run the program, returning the signal strength at every 40th cycle
+/// starting from the 20th, and the rows of the picture drawn on the CRT.
+fn execute(commands: &[Command]) -> (Vec<i32>, Vec<String>) {
+    // Loop over commands and update buffer, printing at every
+    // (cycle-20)%40 == 0 cycles
+    let mut cycle: usize = 0;
+    let mut register: i32 = 1;
+    let mut seen: Vec<i32> = vec![];
+    let mut screen: Vec<String> = vec![];
+    for cmd in commands.iter() {
+        match cmd {
+            Command::Noop => {
+                // mid cycle 1
+
+                if let Some(reg) = advance_cycle(&mut cycle, &register, &mut screen) {
+                    seen.push(cycle as i32 * reg);
+                }
+                // end cycle 1
+            }
+            Command::Addx(val) => {
+                // mid cycle 1
+                if let Some(reg) = advance_cycle(&mut cycle, &register, &mut screen) {
+                    seen.push(cycle as i32 * reg);
+                }
+                // mid cycle 2
+                if let Some(reg) = advance_cycle(&mut cycle, &register, &mut screen) {
+                    seen.push(cycle as i32 * reg);
+                }
+                // end cycle 2
+                register += val;
+            }
+        }
+    }
+    (seen, screen)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_commands(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Answer {
+        part1(commands).into()
+    }
+
+    fn part2(commands: &Self::Input) -> Option<Answer> {
+        Some(part2(commands).into())
+    }
+}
+
+/// The sum of the signal strengths during the interesting cycles.
+pub fn part1(commands: &[Command]) -> i32 {
+    let (seen, _) = execute(commands);
+    debug!("Signal strengths {:?}", seen);
+    seen.iter().sum()
+}
+
+/// The rows of the screen the program draws.
+pub fn part2(commands: &[Command]) -> Vec<String> {
+    let (_, screen) = execute(commands);
+    screen
+}
@@ -0,0 +1,341 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::VecDeque;
+
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::bigint::{self, BigInt, Integer};
+use crate::util::{input, math};
+use crate::{debug, trace};
+
+#[derive(Clone, Copy)]
+enum OpInput {
+    Num(usize),
+    Old,
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Multiply,
+}
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Clone, Copy)]
+struct Operation {
+    operands: [OpInput; 2],
+    operation: Op,
+}
+
+impl Operation {
+    fn apply<W: Integer>(&self, old: &W) -> W {
+        let [a, b] = self.operands.map(|operand| match operand {
+            OpInput::Num(val) => W::from_i64(val as i64),
+            OpInput::Old => old.clone(),
+        });
+        match self.operation {
+            Op::Add => a.add(&b),
+            Op::Multiply => a.mul(&b),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: usize,
+    items: VecDeque<usize>,
+    op: Operation,
+    test_divisor: usize,
+    true_target: usize,
+    false_target: usize,
+    inspections: usize,
+}
+
+impl std::fmt::Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Monkey {} with items {:?} and test/targets {} {} {}",
+            self.id, self.items, self.test_divisor, self.true_target, self.false_target
+        )
+    }
+}
+
+fn parse_op(input: &str, opstring: &str) -> Result<Operation> {
+    let operand = |term: &str| match term {
+        "old" => Ok(OpInput::Old),
+        _ => parse_at(input, term, "`old` or a number").map(OpInput::Num),
+    };
+    let (operands, operation) = match opstring.split(' ').collect::<Vec<&str>>()[..] {
+        [a, op, b] => {
+            let operation = match op {
+                "+" => Op::Add,
+                "*" => Op::Multiply,
+                _ => return Err(AocError::at(input, op, "`+` or `*`")),
+            };
+            ([operand(a)?, operand(b)?], operation)
+        }
+        _ => {
+            return Err(AocError::at(
+                input,
+                opstring,
+                "an operation like `old * 19`",
+            ))
+        }
+    };
+
+    Ok(Operation {
+        operands,
+        operation,
+    })
+}
+
+/// The first number on `line`, reporting the whole line if there isn't one.
+fn number_on(input: &str, line: &str, expected: &str) -> Result<usize> {
+    lazy_static! {
+        // regex for capturing a simple usize, i.e. id, divisor, targets
+        static ref NUM_RE: Regex = Regex::new(r"(\d+)").unwrap();
+    }
+    let num = NUM_RE
+        .find(line)
+        .ok_or_else(|| AocError::at(input, line, expected))?;
+    parse_at(input, num.as_str(), expected)
+}
+
+impl Monkey {
+    fn from_lines(input: &str, lines: &[&str]) -> Result<Monkey> {
+        lazy_static! {
+            // regex for capturing list of usize, i.e. starting items
+            static ref ITEMS_RE: Regex = Regex::new(r"Starting items: ((?:\d+(?:, )?)*)").unwrap();
+            // regex for capturing operation
+            static ref OP_RE: Regex = Regex::new(r"new = (.*)").unwrap();
+        }
+        // Check input came to us correctly
+        let lines: &[&str; 6] = match lines.try_into() {
+            Ok(lines) => lines,
+            Err(_) => {
+                let found = lines.first().copied().unwrap_or(&input[input.len()..]);
+                return Err(AocError::at(input, found, "six lines describing a monkey"));
+            }
+        };
+        if !lines[0].starts_with("Monkey") {
+            return Err(AocError::at(input, lines[0], "`Monkey N:`"));
+        }
+        // Parse lines
+        let id = number_on(input, lines[0], "a monkey number")?;
+        let items = ITEMS_RE
+            .captures(lines[1])
+            .ok_or_else(|| AocError::at(input, lines[1], "`Starting items: ...`"))?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .filter(|x| !x.is_empty())
+            .map(|x| parse_at(input, x, "a worry level"))
+            .collect::<Result<VecDeque<usize>>>()?;
+        let op = parse_op(
+            input,
+            OP_RE
+                .captures(lines[2])
+                .ok_or_else(|| AocError::at(input, lines[2], "`Operation: new = ...`"))?
+                .get(1)
+                .unwrap()
+                .as_str(),
+        )?;
+        let test_divisor = number_on(input, lines[3], "`Test: divisible by N`")?;
+        if test_divisor == 0 {
+            return Err(AocError::at(input, lines[3], "a divisor other than 0"));
+        }
+        let true_target = number_on(input, lines[4], "`If true: throw to monkey N`")?;
+        let false_target = number_on(input, lines[5], "`If false: throw to monkey N`")?;
+
+        Ok(Monkey {
+            id,
+            items,
+            op,
+            test_divisor,
+            true_target,
+            false_target,
+            inspections: 0,
+        })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    const PARAMS: &'static [Param] = &[ROUNDS, RELIEF, LONG_ROUNDS];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let records = input::records(input);
+        let monkeys = records
+            .iter()
+            .map(|lines| Monkey::from_lines(input, lines))
+            .collect::<Result<Vec<Monkey>>>()?;
+        // monkey business takes the two busiest monkeys
+        if monkeys.len() < 2 {
+            return Err(AocError::at_end(input, "at least two monkeys"));
+        }
+        for (monkey, lines) in monkeys.iter().zip(&records) {
+            for (target, line) in [
+                (monkey.true_target, lines[4]),
+                (monkey.false_target, lines[5]),
+            ] {
+                if target >= monkeys.len() {
+                    return Err(AocError::at(
+                        input,
+                        line,
+                        format!("a monkey numbered below {}", monkeys.len()),
+                    ));
+                }
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Answer {
+        part1(monkeys).into()
+    }
+
+    fn part2(monkeys: &Self::Input) -> Option<Answer> {
+        Some(part2(monkeys).into())
+    }
+}
+
+const ROUNDS: Param = Param {
+    day: 11,
+    name: "rounds",
+    default: 20,
+    range: 0..=i64::MAX,
+    help: "the rounds of keep away in part 1",
+};
+
+const RELIEF: Param = Param {
+    day: 11,
+    name: "relief",
+    default: 3,
+    range: 1..=i64::MAX,
+    help: "what worry levels are divided by after each inspection in part 1",
+};
+
+const LONG_ROUNDS: Param = Param {
+    day: 11,
+    name: "long_rounds",
+    default: 10000,
+    range: 0..=i64::MAX,
+    help: "the rounds of keep away in part 2",
+};
+
+/// Worry levels grow quickly when nothing keeps them in check, so with exact arithmetic turned
+/// on they are kept as [`BigInt`]s.
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    let (nrounds, relief) = (ROUNDS.get(), Some(RELIEF.get()));
+    if bigint::exact() {
+        monkey_business::<BigInt>(monkeys, nrounds, relief, None)
+    } else {
+        monkey_business::<usize>(monkeys, nrounds, relief, None)
+    }
+}
+
+/// Worry levels stay below the square of the common multiple of the divisors, so machine
+/// integers are always exact here, and 10000 rounds without the modulus would be far too big
+/// for any arithmetic.
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    let lcm = monkeys
+        .iter()
+        .fold(1, |lcm, monkey| math::lcm(lcm, monkey.test_divisor));
+    monkey_business::<usize>(monkeys, LONG_ROUNDS.get(), None, Some(lcm))
+}
+
+/// Play `nrounds` rounds of keep away, keeping worry levels as `W`. With `relief`, worry levels
+/// are divided by it after every inspection. With a `modulus`, they are kept in check by taking
+/// the remainder modulo it, which doesn't change where items are thrown as long as it is a
+/// multiple of every monkey's test divisor.
+///
+/// Returns the product of the two highest inspection counts.
+fn monkey_business<W: Integer>(
+    monkeys: &[Monkey],
+    nrounds: usize,
+    relief: Option<usize>,
+    modulus: Option<usize>,
+) -> usize {
+    let mut monkeys = monkeys.to_vec();
+    let number = |n: usize| W::from_i64(n as i64);
+    let mut items: Vec<VecDeque<W>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|&item| number(item)).collect())
+        .collect();
+    let modulus = modulus.map(number);
+    let relief = relief.map(number);
+    let zero = number(0);
+    // Execute monkey loop
+    for _ in 0..nrounds {
+        for i in 0..monkeys.len() {
+            let monkey = &mut monkeys[i];
+            let divisor = number(monkey.test_divisor);
+            trace!("Monkey {}:", monkey.id);
+            let mut item_buffer: Vec<W> = vec![];
+            let mut target_buffer: Vec<usize> = vec![];
+            while let Some(item) = items[i].pop_front() {
+                trace!("  Monkey inspects an item with a worry level of {}.", item);
+                monkey.inspections += 1;
+                let item = monkey.op.apply(&item);
+                trace!("    Worry level becomes {}.", item);
+                let item = match (&relief, &modulus) {
+                    (Some(relief), _) => item.div(relief),
+                    (None, Some(modulus)) => item.rem(modulus),
+                    (None, None) => item,
+                };
+                if let Some(relief) = &relief {
+                    trace!(
+                        "    Monkey gets bored with item. Worry level is divided by {} to {}.",
+                        relief,
+                        item
+                    );
+                }
+                let target = if item.rem(&divisor) == zero {
+                    trace!(
+                        "    Current worry level is divisible by {}.",
+                        monkey.test_divisor
+                    );
+                    trace!(
+                        "    Item with worry level {} is thrown to monkey {}.",
+                        item,
+                        monkey.true_target
+                    );
+                    monkey.true_target
+                } else {
+                    trace!(
+                        "    Current worry level is not divisible by {}.",
+                        monkey.test_divisor
+                    );
+                    trace!(
+                        "    Item with worry level {} is thrown to monkey {}.",
+                        item,
+                        monkey.false_target
+                    );
+                    monkey.false_target
+                };
+                item_buffer.push(item);
+                target_buffer.push(target);
+            }
+            // throw out items
+            for (item, target) in item_buffer.into_iter().zip(target_buffer) {
+                items[target].push_back(item);
+            }
+        }
+    }
+
+    for monkey in monkeys.iter() {
+        debug!(
+            "Monkey {} inspected items {} times.",
+            monkey.id, monkey.inspections
+        );
+    }
+    let mut inspections: Vec<usize> = monkeys.iter().map(|x| x.inspections).collect();
+    inspections.sort_by(|a, b| b.cmp(a));
+    inspections[0] * inspections[1]
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use crate::error::AocError;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::util::search;
+use crate::{debug, trace};
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    accessible: Vec<(usize, usize)>,
+}
+
+/// Heights of every square on the map (`map_raw`), the squares accessible from
+/// each square (`map`), and the start and end positions.
+pub type Map = (Grid<usize>, Grid<Tile>, (usize, usize), (usize, usize));
+
+fn read_map(input: &str) -> Result<Map> {
+    let charmap = HashMap::from([
+        ('a', 0),
+        ('b', 1),
+        ('c', 2),
+        ('d', 3),
+        ('e', 4),
+        ('f', 5),
+        ('g', 6),
+        ('h', 7),
+        ('i', 8),
+        ('j', 9),
+        ('k', 10),
+        ('l', 11),
+        ('m', 12),
+        ('n', 13),
+        ('o', 14),
+        ('p', 15),
+        ('q', 16),
+        ('r', 17),
+        ('s', 18),
+        ('t', 19),
+        ('u', 20),
+        ('v', 21),
+        ('w', 22),
+        ('x', 23),
+        ('y', 24),
+        ('z', 25),
+        ('E', 25),
+        ('S', 0),
+    ]);
+
+    let chars = Grid::parse(
+        input,
+        &input::lines(input),
+        "a height from a to z, S or E",
+        |c| charmap.contains_key(&c).then_some(c),
+    )?;
+    let find = |target: char| chars.iter().find(|(_, &c)| c == target).map(|(pos, _)| pos);
+    let start = find('S').ok_or_else(|| AocError::at_end(input, "a start position `S`"))?;
+    let end = find('E').ok_or_else(|| AocError::at_end(input, "a best signal position `E`"))?;
+
+    let map_raw = chars.map(|c| charmap[c]);
+    let mut map = Grid::new(
+        map_raw.height(),
+        map_raw.width(),
+        Tile { accessible: vec![] },
+    );
+    for pos in map_raw.positions() {
+        // we can step to any neighbour at most one higher than us
+        map[pos].accessible = map_raw
+            .neighbours4(pos)
+            .filter(|&next| map_raw[next] as i32 - map_raw[pos] as i32 <= 1)
+            .collect();
+    }
+    Ok((map_raw, map, start, end))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Option<Answer> {
+        Some(part2(map).into())
+    }
+}
+
+pub fn part1((map_raw, map, start, end): &Map) -> usize {
+    let end = *end;
+    let search = search::bfs(
+        *start,
+        |&pos| map[pos].accessible.iter().copied(),
+        |&pos| pos == end,
+    );
+    let route = search.path().unwrap();
+    trace!("{}", draw_route(map_raw, &route));
+
+    debug!("Found exit in {} steps", route.len() - 1);
+    route.len() - 1
+}
+
+pub fn part2((map_raw, map, _, end): &Map) -> usize {
+    let end = *end;
+    // Search outwards from every square at elevation `a` at once, so the first to reach the end
+    // is the closest
+    let starts = map_raw
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(pos, _)| pos);
+    let search = search::bfs_multi(
+        starts,
+        |&pos| map[pos].accessible.iter().copied(),
+        |&pos| pos == end,
+    );
+    let route = search.path().unwrap();
+    trace!("{}", draw_route(map_raw, &route));
+
+    debug!(
+        "Found exit in {} steps from {:?}",
+        route.len() - 1,
+        route[0]
+    );
+    route.len() - 1
+}
+
+/// Draw `route` over a map the size of `heights`.
+fn draw_route(heights: &Grid<usize>, route: &[(usize, usize)]) -> Grid<char> {
+    let mut output = Grid::new(heights.height(), heights.width(), '.');
+    for &pos in route {
+        output[pos] = '#';
+    }
+    output[route[0]] = 'S';
+    output
+}
@@ -1,17 +1,20 @@
 use std::cmp::Ordering;
-use std::{
-    collections::VecDeque,
-    fs::File,
-    io::{self, BufRead},
-};
+use std::collections::VecDeque;
 
+use crate::error::{char_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::{debug, trace};
+
+/// A packet, or one of the integers and lists inside it.
 #[derive(Debug)]
-enum PacketEntry {
+pub enum PacketEntry {
     List(VecDeque<PacketEntry>),
     Integer(usize),
 }
 
-fn compare_packetentry(a: PacketEntry, b: PacketEntry) -> Option<bool> {
+/// Whether `a` and `b` are in the right order, or `None` if they are equal.
+pub fn compare_packetentry(a: PacketEntry, b: PacketEntry) -> Option<bool> {
     match (a, b) {
         (PacketEntry::List(a_l), PacketEntry::List(b_l)) => compare_list_to_list(a_l, b_l),
         (PacketEntry::List(a_l), PacketEntry::Integer(b_i)) => compare_list_to_int(a_l, b_i),
@@ -51,14 +54,14 @@ fn compare_list_to_list(
 ) -> Option<bool> {
     loop {
         // check we have items to compare
-        if a.len() == 0 {
+        if a.is_empty() {
             // either a exhausted first or both exhausted simultaneously
-            if b.len() == 0 {
+            if b.is_empty() {
                 // both exhausted simultaneously
                 return None;
             }
             return Some(true);
-        } else if b.len() == 0 {
+        } else if b.is_empty() {
             // b exhausted first
             return Some(false);
         }
@@ -72,7 +75,8 @@ fn compare_list_to_list(
     }
 }
 
-fn parse_list_from_string(line: &String) -> PacketEntry {
+/// Read a packet from a line that [`Day13::parse`] has already checked.
+pub fn parse_list_from_string(line: &str) -> PacketEntry {
     let mut out: VecDeque<PacketEntry> = VecDeque::from(vec![]);
     let chars = line.chars();
     let mut parsing_num = false; // flag to check if im parsing a number
@@ -107,13 +111,11 @@ fn parse_list_from_string(line: &String) -> PacketEntry {
                 }
             }
             ',' => {
-                if !consuming_interior {
-                    if parsing_num {
-                        parsing_num = false;
-                        let packet_num = PacketEntry::Integer(current_num);
-                        current_num = 0;
-                        out.push_back(packet_num);
-                    }
+                if !consuming_interior && parsing_num {
+                    parsing_num = false;
+                    let packet_num = PacketEntry::Integer(current_num);
+                    current_num = 0;
+                    out.push_back(packet_num);
                 }
             } // next element,
             _ => {
@@ -140,37 +142,93 @@ fn parse_list_from_string(line: &String) -> PacketEntry {
     PacketEntry::List(out)
 }
 
-fn main() {
-    // Init input reader
-    let file = File::open("day13_1.txt").unwrap();
-    let filebuf = io::BufReader::new(file);
-    let all_lines: Vec<String> = filebuf.lines().map(|x| x.unwrap()).collect();
+/// Check that `line` is a single well formed packet, so that parse_list_from_string can take it
+/// on trust.
+fn check_packet(input: &str, line: &str) -> Result<()> {
+    let mut depth = 0;
+    for (i, char) in line.char_indices() {
+        let found = char_at(line, i);
+        match char {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            ',' | '0'..='9' if depth > 0 => {}
+            _ if depth == 0 => {
+                return Err(AocError::at(input, found, "a packet starting with `[`"))
+            }
+            _ => return Err(AocError::at(input, found, "`[`, `]`, `,` or a digit")),
+        }
+        if depth == 0 && i + 1 < line.len() {
+            let rest = &line[i + 1..];
+            return Err(AocError::at(input, rest, "the end of the packet"));
+        }
+    }
+    if depth > 0 {
+        return Err(AocError::at(input, &line[line.len()..], "`]`"));
+    }
+    Ok(())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    /// Every line of the input, including the blanks between pairs.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+        for (i, line) in lines.iter().enumerate() {
+            // every pair of packets is followed by a blank line
+            if i % 3 == 2 {
+                if !line.is_empty() {
+                    return Err(AocError::at(input, line, "a blank line between pairs"));
+                }
+            } else {
+                check_packet(input, line)?;
+            }
+        }
+        Ok(lines.iter().map(|x| x.to_string()).collect())
+    }
+
+    fn part1(all_lines: &Self::Input) -> Answer {
+        part1(all_lines).into()
+    }
+
+    fn part2(all_lines: &Self::Input) -> Option<Answer> {
+        Some(part2(all_lines).into())
+    }
+}
+
+pub fn part1(all_lines: &[String]) -> usize {
     // Split lines into pairs of packets
     let mut accumulator = 0;
-    // Part 1
     for i in 0..(all_lines.len() + 1) / 3 {
         let lines: Vec<&String> = all_lines[i * 3..i * 3 + 2].iter().collect();
 
         let l1 = parse_list_from_string(lines[0]);
         let l2 = parse_list_from_string(lines[1]);
-        println!("{:?}", l1);
-        println!("{:?}", l2);
+        trace!("{:?}", l1);
+        trace!("{:?}", l2);
         match compare_packetentry(l1, l2) {
             Some(b) => match b {
                 true => {
-                    println!("Pair {} is in the right order", i + 1);
+                    trace!("Pair {} is in the right order", i + 1);
                     accumulator += i + 1;
                 }
                 false => {
-                    println!("Pair {} is not in the right order", i + 1)
+                    trace!("Pair {} is not in the right order", i + 1)
                 }
             },
             None => unreachable!(),
         }
-        println!("--------------");
     }
-    println!("Final answer for part 1 is {}", accumulator);
-    // Part 2
+    debug!(
+        "Sum of the indices of pairs in the right order is {}",
+        accumulator
+    );
+    accumulator
+}
+
+pub fn part2(all_lines: &[String]) -> usize {
     let mut cleaned_lines: Vec<&String> = all_lines.iter().filter(|x| !x.is_empty()).collect();
     let indicator1 = String::from("[[2]]");
     let indicator2 = String::from("[[6]]");
@@ -186,12 +244,16 @@ fn main() {
         }
     });
 
+    let mut decoder_key = 1;
     for (i, line) in cleaned_lines.iter().enumerate() {
         if line == &&String::from("[[2]]") {
-            println!("Indicator 1 at position {}", i + 1);
+            debug!("Indicator 1 at position {}", i + 1);
+            decoder_key *= i + 1;
         }
         if line == &&String::from("[[6]]") {
-            println!("Indicator 2 at position {}", i + 1);
+            debug!("Indicator 2 at position {}", i + 1);
+            decoder_key *= i + 1;
         }
     }
+    decoder_key
 }
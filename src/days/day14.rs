@@ -0,0 +1,295 @@
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::stepper::{Simulation, Steppable, Stepper};
+use crate::util::geometry::Point2;
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::util::render::{Picture, Rgb, Style};
+use crate::{debug, trace};
+
+/// Cells of the cave blocked by rock, and the column sand enters at.
+pub type Cave = (Grid<bool>, usize);
+
+#[derive(Debug)]
+enum Wall {
+    Horizontal(Point2<usize>, Point2<usize>),
+    Vertical(Point2<usize>, Point2<usize>),
+}
+
+/// Given a line "x,y -> x,y -> ...", return the corners of the path it traces
+fn parse_path(input: &str, line: &str) -> Result<Vec<Point2<usize>>> {
+    let mut coords: Vec<Point2<usize>> = vec![];
+    for point in line.split(" -> ") {
+        let (x, y) = point
+            .split_once(',')
+            .ok_or_else(|| AocError::at(input, point, "a point like 498,4"))?;
+        let coord = Point2::new(
+            parse_at(input, x, "an x coordinate")?,
+            parse_at(input, y, "a y coordinate")?,
+        );
+        if let Some(prev) = coords.last() {
+            if prev.x != coord.x && prev.y != coord.y {
+                return Err(AocError::at(
+                    input,
+                    point,
+                    "a point in line with the one before",
+                ));
+            }
+        }
+        coords.push(coord);
+    }
+    Ok(coords)
+}
+
+fn coords_to_walls(coords: &[Point2<usize>], xoffset: usize, yoffset: usize) -> Vec<Wall> {
+    let origin = Point2::new(xoffset, yoffset);
+    let mut walls: Vec<Wall> = vec![];
+    let mut coord_it = coords.iter().peekable();
+    while let Some(coord) = coord_it.next() {
+        let peek_next_coord = coord_it.peek();
+        let next_coord = match peek_next_coord {
+            Some(&val) => val,
+            None => break,
+        };
+        if next_coord.x == coord.x {
+            // Wall is vertical
+            walls.push(Wall::Vertical(*coord - origin, *next_coord - origin));
+        } else {
+            // Wall is horizontal
+            walls.push(Wall::Horizontal(*coord - origin, *next_coord - origin));
+        }
+    }
+
+    walls
+}
+
+fn add_wall_to_map(map: &mut Grid<bool>, wall: Wall) {
+    match wall {
+        Wall::Horizontal(start, end) => {
+            let y = start.y;
+            for i in if start.x < end.x {
+                start.x..end.x + 1
+            } else {
+                end.x..start.x + 1
+            } {
+                map[(y, i)] = true;
+            }
+        }
+        Wall::Vertical(start, end) => {
+            let x = start.x;
+            for i in if start.y < end.y {
+                start.y..end.y + 1
+            } else {
+                end.y..start.y + 1
+            } {
+                map[(i, x)] = true;
+            }
+        }
+    }
+}
+
+/// The cave as the puzzle draws it, with the sand that has settled in `filled`.
+fn draw_cave((map, entry_point): &Cave, filled: &Grid<bool>) -> Picture {
+    let floor = filled.height() - 1;
+    Picture::from_fn(filled.height(), filled.width(), |pos| {
+        if pos == (0, *entry_point) && !filled[pos] {
+            Style::new('+', Rgb::YELLOW)
+        } else if map[pos] || (filled[pos] && pos.0 == floor) {
+            Style::new('#', Rgb::BROWN)
+        } else if filled[pos] {
+            Style::new('o', Rgb::SAND)
+        } else {
+            Style::new('.', Rgb::DARK)
+        }
+    })
+}
+
+/// Returns where the sand comes to rest if it is added to the map succesfully, `None` otherwise
+/// (sand falls off). Also return `None` if sand gets stuck at the entry point
+fn drop_sand(map: &mut Grid<bool>, entry_point: usize) -> Option<Point2<usize>> {
+    let mut curr_pos = Point2::new(entry_point, 0);
+    if map[curr_pos.pos()] {
+        // entry point blocked
+        return None;
+    }
+    loop {
+        let below = curr_pos + Point2::new(0, 1);
+        if below.y == map.height() {
+            // nothing below us, fall into the abyss
+            return None;
+        }
+        if map[below.pos()] {
+            // cell below blocked
+            if curr_pos.x == 0 {
+                // handle left boundary, fall off left
+                return None;
+            }
+            let below_left = below - Point2::new(1, 0);
+            if map[below_left.pos()] {
+                // cell left blocked
+                if curr_pos.x == map.width() - 1 {
+                    // handle right boundary, fall off right
+                    return None;
+                }
+                let below_right = below + Point2::new(1, 0);
+                if map[below_right.pos()] {
+                    // cell right blocked, stick
+                    map[curr_pos.pos()] = true;
+                    trace!("Added sand at {} {}", curr_pos.x, curr_pos.y);
+                    return Some(curr_pos);
+                } else {
+                    // cell right unblocked, fall
+                    curr_pos = below_right
+                }
+            } else {
+                // cell left unblocked, fall
+                curr_pos = below_left
+            }
+        } else {
+            // cell below unblocked, fall
+            curr_pos = below
+        }
+    }
+}
+
+/// Build the map of the cave, returning it along with the column sand enters at. Sand falling
+/// below the lowest wall drops out of the bottom of the map; the infinite floor two rows below
+/// it is only added for part 2.
+fn build_map(input: &str) -> Result<Cave> {
+    let paths = input::lines(input)
+        .iter()
+        .map(|line| parse_path(input, line))
+        .collect::<Result<Vec<_>>>()?;
+    if paths.is_empty() {
+        return Err(AocError::at_end(input, "a path of rock"));
+    }
+    // Loop through lines and populate array of walls
+    // we also want to find the max/min extent of the map in each direction while we're at it
+    let mut walls: Vec<Wall> = vec![];
+    let mut x_max = 0;
+    let mut x_min = 10000;
+    let mut y_max = 0;
+    let mut y_min = 0;
+    for &Point2 { x, y } in paths.iter().flatten() {
+        if x > x_max {
+            x_max = x;
+        }
+        if x < x_min {
+            x_min = x;
+        }
+        if y > y_max {
+            y_max = y;
+        }
+        if y < y_min {
+            y_min = y;
+        }
+    }
+    debug!("x in ({}, {}), y in ({}, {})", x_min, x_max, y_min, y_max);
+
+    let height = 2 + y_max - y_min;
+    let width = height * 2 + (x_max - x_min);
+
+    for path in paths.iter() {
+        walls.append(&mut coords_to_walls(path, x_min - height, y_min));
+    }
+
+    // Loop over array of walls and construct map
+    let mut map = Grid::new(height + 1, width + 1, false);
+    for wall in walls {
+        trace!("{:?}", wall);
+        add_wall_to_map(&mut map, wall);
+    }
+    let entry_point = 500 - x_min + height;
+    Ok((map, entry_point))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        build_map(input)
+    }
+
+    fn part1(cave: &Self::Input) -> Answer {
+        part1(cave).into()
+    }
+
+    fn part2(cave: &Self::Input) -> Option<Answer> {
+        Some(part2(cave).into())
+    }
+
+    /// The sand piled up against the floor, once it has blocked the entry point.
+    fn render(cave: &Self::Input) -> Option<Picture> {
+        let (filled, _) = pour_sand(cave, true);
+        Some(draw_cave(cave, &filled))
+    }
+
+    /// Part 1's sand, a unit at a time. `x` and `y` are where the last unit landed, counting
+    /// from the top left of the map.
+    fn simulate(cave: &Self::Input) -> Option<Box<dyn Steppable>> {
+        Some(Box::new(Stepper::new(Pouring { cave: cave.clone() })))
+    }
+}
+
+/// Sand pouring into the cave of part 1, one unit at a time.
+struct Pouring {
+    cave: Cave,
+}
+
+impl Simulation for Pouring {
+    /// The cave with the sand in it, how many units there are, and where the last one landed.
+    type State = (Grid<bool>, usize, Option<Point2<usize>>);
+
+    fn start(&self) -> Self::State {
+        (self.cave.0.clone(), 0, None)
+    }
+
+    fn step(&self, (filled, units, _): &Self::State) -> Option<Self::State> {
+        let mut filled = filled.clone();
+        let landed = drop_sand(&mut filled, self.cave.1)?;
+        Some((filled, units + 1, Some(landed)))
+    }
+
+    fn draw(&self, (filled, _, _): &Self::State) -> Picture {
+        draw_cave(&self.cave, filled)
+    }
+
+    fn values(&self, &(_, units, landed): &Self::State) -> Vec<(&'static str, i64)> {
+        let landed = landed.map_or((-1, -1), |pos| (pos.x as i64, pos.y as i64));
+        vec![("units", units as i64), ("x", landed.0), ("y", landed.1)]
+    }
+}
+
+/// Pour sand into the cave until no more comes to rest, returning the cave with the sand in it
+/// and how many units there are. With a `floor`, that is once the entry point is blocked.
+fn pour_sand((map, entry_point): &Cave, floor: bool) -> (Grid<bool>, usize) {
+    let mut map = map.clone();
+    if floor {
+        // add infinite wall
+        let height = map.height() - 1;
+        let width = map.width() - 1;
+        add_wall_to_map(
+            &mut map,
+            Wall::Horizontal(Point2::new(0, height), Point2::new(width, height)),
+        );
+    }
+    let mut units = 0;
+    while drop_sand(&mut map, *entry_point).is_some() {
+        units += 1;
+    }
+    (map, units)
+}
+
+pub fn part1(cave: &Cave) -> usize {
+    let (filled, units) = pour_sand(cave, false);
+    trace!("{}", draw_cave(cave, &filled));
+    units
+}
+
+pub fn part2(cave: &Cave) -> usize {
+    let (filled, units) = pour_sand(cave, true);
+    trace!("{}", draw_cave(cave, &filled));
+    units
+}
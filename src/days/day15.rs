@@ -0,0 +1,243 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::debug;
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::repl::Query;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::geometry::Point2;
+use crate::util::input;
+use crate::util::interval::IntervalSet;
+use crate::util::par::par_find_first;
+use crate::util::render::{Picture, Rgb, Style};
+
+/// Every sensor, and the beacon each one is closest to.
+pub type Sensors = (Vec<Sensor>, Vec<Point2<i32>>);
+
+pub struct Sensor {
+    pub pos: Point2<i32>,
+    /// The distance to the closest beacon, which is how far the sensor scans.
+    pub beacon_dist: i32,
+}
+
+/// The most cells across or down a picture of the region, which is millions of squares wide on
+/// the real input.
+const MAX_CELLS: i32 = 200;
+
+/// Corners of the smallest rectangle holding every square a sensor scans.
+fn scanned_extent(sensors: &[Sensor]) -> (Point2<i32>, Point2<i32>) {
+    let reach = |sensor: &Sensor| Point2::new(sensor.beacon_dist, sensor.beacon_dist);
+    let min = sensors.iter().map(|sensor| sensor.pos - reach(sensor));
+    let max = sensors.iter().map(|sensor| sensor.pos + reach(sensor));
+    (
+        Point2::new(
+            min.clone().map(|p| p.x).min().unwrap(),
+            min.map(|p| p.y).min().unwrap(),
+        ),
+        Point2::new(
+            max.clone().map(|p| p.x).max().unwrap(),
+            max.map(|p| p.y).max().unwrap(),
+        ),
+    )
+}
+
+/// The sensors, beacons and scanned squares between the corners `min` and `max`, as the puzzle
+/// draws them. Big regions are scaled down so that each cell covers a square block, which shows
+/// any sensor or beacon inside it and is otherwise drawn as scanned if its middle is.
+fn draw_region(
+    sensors: &[Sensor],
+    beacons: &[Point2<i32>],
+    min: Point2<i32>,
+    max: Point2<i32>,
+) -> Picture {
+    let size = max - min + Point2::new(1, 1);
+    let block = (size.x.max(size.y) + MAX_CELLS - 1) / MAX_CELLS;
+    let cells = |len: i32| ((len + block - 1) / block) as usize;
+    Picture::from_fn(cells(size.y), cells(size.x), |(row, col)| {
+        let corner = min + Point2::new(col as i32, row as i32) * block;
+        let inside = |point: &Point2<i32>| {
+            let offset = *point - corner;
+            (0..block).contains(&offset.x) && (0..block).contains(&offset.y)
+        };
+        if beacons.iter().any(inside) {
+            Style::new('B', Rgb::BLUE)
+        } else if sensors.iter().any(|sensor| inside(&sensor.pos)) {
+            Style::new('S', Rgb::RED)
+        } else {
+            let middle = corner + Point2::new(block / 2, block / 2);
+            if sensors
+                .iter()
+                .any(|sensor| sensor.pos.manhattan(middle) <= sensor.beacon_dist)
+            {
+                Style::new('#', Rgb::GREY)
+            } else {
+                Style::new('.', Rgb::DARK)
+            }
+        }
+    })
+}
+
+fn read_sensors(input: &str) -> Result<Sensors> {
+    lazy_static! {
+        static ref SENS_RE: Regex =
+            Regex::new(r"x=(-?\d+), y=(-?\d+).+x=(-?\d+), y=(-?\d+)").unwrap();
+    }
+    let all_lines = input::lines(input);
+    //
+    let mut sensors: Vec<Sensor> = vec![];
+    let mut beacons: Vec<Point2<i32>> = vec![];
+    for line in all_lines {
+        let captures = SENS_RE.captures(line).ok_or_else(|| {
+            AocError::at(
+                input,
+                line,
+                "`Sensor at x=X, y=Y: closest beacon is at x=X, y=Y`",
+            )
+        })?;
+        let coord = |i| parse_at::<i32>(input, captures.get(i).unwrap().as_str(), "a coordinate");
+        let pos = Point2::new(coord(1)?, coord(2)?);
+        let beacon_pos = Point2::new(coord(3)?, coord(4)?);
+        sensors.push(Sensor {
+            pos,
+            beacon_dist: pos.manhattan(beacon_pos),
+        });
+        beacons.push(beacon_pos);
+    }
+
+    if sensors.is_empty() {
+        return Err(AocError::at_end(input, "at least one sensor"));
+    }
+    let (min, max) = scanned_extent(&sensors);
+    debug!("xmin: {}, xmax: {}", min.x, max.x);
+    debug!("ymin: {}, ymax: {}", min.y, max.y);
+
+    Ok((sensors, beacons))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sensors;
+
+    const PARAMS: &'static [Param] = &[PROBE_ROW, SEARCH_MAX];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_sensors(input)
+    }
+
+    fn part1(sensors: &Self::Input) -> Answer {
+        part1(sensors).into()
+    }
+
+    fn part2(sensors: &Self::Input) -> Option<Answer> {
+        Some(part2(sensors).into())
+    }
+
+    /// Everywhere the sensors scan.
+    fn render((sensors, beacons): &Self::Input) -> Option<Picture> {
+        let (min, max) = scanned_extent(sensors);
+        Some(draw_region(sensors, beacons, min, max))
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![Query {
+            name: "row",
+            args: "Y [SENSOR]",
+            help: "the squares scanned on a row, by every sensor or the Nth",
+            run: |(sensors, beacons), args| {
+                let (row, sensor) = args.split_once(' ').unwrap_or((args, ""));
+                let row: i32 = row.parse().map_err(|_| format!("'{}' is not a row", row))?;
+                let sensors = match sensor.trim() {
+                    "" => sensors,
+                    n => match n.parse::<usize>() {
+                        Ok(n) if (1..=sensors.len()).contains(&n) => &sensors[n - 1..n],
+                        _ => {
+                            return Err(format!(
+                                "'{}' is not a sensor, there are {}",
+                                n,
+                                sensors.len()
+                            ))
+                        }
+                    },
+                };
+                let scanned = scanned_on_row(sensors, row);
+                let ranges: Vec<String> = scanned
+                    .ranges()
+                    .iter()
+                    .map(|range| format!("{}..={}", range.start, range.end - 1))
+                    .collect();
+                // a beacon can be the closest to more than one sensor
+                let mut found: Vec<Point2<i32>> = vec![];
+                for beacon in beacons.iter() {
+                    if beacon.y == row && scanned.contains(beacon.x) && !found.contains(beacon) {
+                        found.push(*beacon);
+                    }
+                }
+                Ok(format!(
+                    "{} squares scanned, {} with a beacon: {}",
+                    scanned.len(),
+                    found.len(),
+                    ranges.join(", ")
+                ))
+            },
+        }]
+    }
+}
+
+const PROBE_ROW: Param = Param {
+    day: 15,
+    name: "probe_row",
+    default: 2000000,
+    range: i32::MIN as i64..=i32::MAX as i64,
+    help: "the row to count the squares a beacon can't be on in",
+};
+
+/// Sensors searching for the distress beacon are only interested in x and y up to this.
+const SEARCH_MAX: Param = Param {
+    day: 15,
+    name: "search_max",
+    default: 4000000,
+    range: 0..=i32::MAX as i64,
+    help: "the largest x and y the distress beacon can have",
+};
+
+pub fn part1((sensors, beacons): &Sensors) -> i32 {
+    // Part 1, count the blocked squares on probe row
+    let probe_row = PROBE_ROW.get::<i32>();
+    let scanned = scanned_on_row(sensors, probe_row);
+    let mut beacons_in_probe_row: Vec<Point2<i32>> = vec![];
+    for beacon in beacons.iter() {
+        if !beacons_in_probe_row.contains(beacon) && beacon.y == probe_row {
+            beacons_in_probe_row.push(*beacon);
+        }
+    }
+    scanned.len() - beacons_in_probe_row.len() as i32
+}
+
+pub fn part2((sensors, _): &Sensors) -> i64 {
+    // Part 2, find the one unscanned square in the search region. Every row is scanned
+    // separately, so the rows are shared out between threads.
+    let search_max = SEARCH_MAX.get::<i32>();
+    let found = par_find_first(0..search_max as usize + 1, |probe_row| {
+        let probe_row = probe_row as i32;
+        let unscanned = scanned_on_row(sensors, probe_row).complement(0..search_max + 1);
+        let range = unscanned.ranges().first()?;
+        Some(range.start as i64 * 4000000_i64 + probe_row as i64)
+    });
+    found.expect("Every square in the search region is scanned")
+}
+
+/// Find the squares scanned on the probe_row.
+pub fn scanned_on_row(sensors: &[Sensor], probe_row: i32) -> IntervalSet<i32> {
+    // for each sensor, get the xrange occupied by its scan radius on the probe row
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let row_dist: i32 = (probe_row - sensor.pos.y).abs(); // how many rows from sensor to probe?
+            let scan_width: i32 = sensor.beacon_dist - row_dist; // how long is the chord formed on this row?
+                                                                 // skip sensors that don't scan this row at all
+            (scan_width >= 0).then(|| sensor.pos.x - scan_width..sensor.pos.x + scan_width + 1)
+        })
+        .collect()
+}
@@ -0,0 +1,216 @@
+use lazy_static::lazy_static;
+use ndarray::Array3;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::util::par::par_map;
+
+/// The best pressure release from every time, valve and set of closed valves, along with the
+/// index of valve AA, the number of sets of closed valves, and the minutes each part has, as
+/// they were when it was solved.
+pub struct ValueFunction {
+    opt: Array3<usize>,
+    aa_idx: usize,
+    mm: usize,
+    minutes: usize,
+    elephant_minutes: usize,
+}
+
+const MINUTES: Param = Param {
+    day: 16,
+    name: "minutes",
+    default: 30,
+    range: 1..=i64::MAX,
+    help: "the minutes before the volcano erupts",
+};
+
+const ELEPHANT_MINUTES: Param = Param {
+    day: 16,
+    name: "elephant_minutes",
+    default: 26,
+    range: 1..=i64::MAX,
+    help: "the minutes left after teaching an elephant to help",
+};
+
+struct Valve<'a> {
+    name: &'a str,
+    rate: usize,
+    neighbours: Vec<&'a str>,
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<&str, usize>, Vec<Valve<'_>>)> {
+    lazy_static! {
+        static ref VALVE_RE: Regex = Regex::new(r"Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels? leads? to valves? ((?:[A-Z][A-Z](?:, )?)+)").unwrap();
+    }
+    let mut valves = vec![];
+    for line in input::lines(input) {
+        let valve_capture = VALVE_RE.captures(line).ok_or_else(|| {
+            AocError::at(
+                input,
+                line,
+                "`Valve XX has flow rate=N; tunnels lead to valves YY, ZZ`",
+            )
+        })?;
+        let rate = parse_at(input, valve_capture.get(2).unwrap().as_str(), "a flow rate")?;
+        let neighbours_str = valve_capture.get(3).unwrap().as_str();
+        let neighbours = neighbours_str.split(", ").collect();
+        valves.push(Valve {
+            name: valve_capture.get(1).unwrap().as_str(),
+            rate,
+            neighbours,
+        });
+    }
+    // Sort valves by rate, useful for searching later
+    valves.sort_by_key(|a| Reverse(a.rate));
+    let valve_map = valves
+        .iter()
+        .enumerate()
+        .map(|(i, valve)| (valve.name, i))
+        .collect::<HashMap<&str, usize>>();
+    for neighbour in valves.iter().flat_map(|v| v.neighbours.iter()) {
+        if !valve_map.contains_key(neighbour) {
+            return Err(AocError::at(input, neighbour, "the name of a valve"));
+        }
+    }
+
+    Ok((valve_map, valves))
+}
+
+/// Solve the dynamic programming problem for the valves described by `input`, for as many minutes
+/// as whichever part has longer.
+fn value_function(input: &str) -> Result<ValueFunction> {
+    // Parse input and find ID of start
+    let (valve_map, valves) = parse_input(input)?;
+    let aa_idx = *valve_map
+        .get("AA")
+        .ok_or_else(|| AocError::at_end(input, "a valve named AA"))?;
+
+    // number of non trivial valves
+    let m = valves.iter().filter(|v| v.rate > 0).count();
+    let n = valves.len();
+    let mm = 1 << m; //bitset for valves being open(0) / closed(1), 2^m
+
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; n];
+    for v in valves.iter() {
+        let i = valve_map[v.name];
+        for neighbour in v.neighbours.iter() {
+            adjacency[i].push(valve_map[neighbour]);
+        }
+    }
+
+    // enough time steps for whichever part has longer
+    let (part1_minutes, elephant_minutes) = (MINUTES.get::<usize>(), ELEPHANT_MINUTES.get());
+    let minutes = part1_minutes.max(elephant_minutes);
+    let mut opt = Array3::<usize>::zeros([minutes, n, mm]);
+    // This loop works by iterating backwards through time. We start at all possible final
+    // configurations. This allows us to recursively calculate 'what is the optimal value
+    // of being in this configuration at a given time?'.
+    //
+    // At the final timestep, all possible configurations have the same value. We cannot gain
+    // anything more once the problem is over.
+    //
+    // Going one time step back, we can enumerate the set of possible configurations from which
+    // the next configuration could have been reached. This is
+    // every config in which a valve was off and we turned it on, or every config in which
+    // we were at a neighbouring valve and moved to this valve.
+    //
+    // Doing this iteratively we end up with an array opt[(time left, current valve, current state)]
+    // which gives the maximum future return for each possible value of those variables. From there,
+    // we just have to check the maximum future return of (30 mins left, "AA", all valves closed)
+    // and we have the answer!
+    //
+    // Each time step only looks at the one after it, so the valves within a step are shared out
+    // between threads.
+    let valve_indices: Vec<usize> = (0..n).collect();
+    for t in 1..minutes {
+        // loop over time steps
+        let layer = par_map(&valve_indices, |&i| {
+            // loop over each valve, calculating value function assuming I am
+            // at this valve.
+            let ii = 1 << i; // bit mask for this valve being opened
+            let mut values = vec![0; mm];
+            for (x, value) in values.iter_mut().enumerate() {
+                // loop over possible configurations
+                let mut curr_val = 0;
+                // is valve i closed in this config?
+                if (ii & x) != 0 && t >= 2 {
+                    // the possible return of turning valve i on at this timestep is the possible
+                    // return of the config which is reached after having turned this
+                    // valve on plus the return from turning this valve on
+                    curr_val = curr_val.max(opt[(t - 1, i, x - ii)] + valves[i].rate * t);
+                }
+                // I might gain more on this step from moving to an adjacent valve, can enumerate
+                // over value function from the future frame to determine this (it might also
+                // just not be possible to open a valve in this room in this state, so I have to
+                // move).
+                for &j in adjacency[i].iter() {
+                    curr_val = curr_val.max(opt[(t - 1, j, x)]);
+                }
+                *value = curr_val;
+            }
+            values
+        });
+        for (i, values) in layer.into_iter().enumerate() {
+            for (x, value) in values.into_iter().enumerate() {
+                opt[(t, i, x)] = value;
+            }
+        }
+    }
+    Ok(ValueFunction {
+        opt,
+        aa_idx,
+        mm,
+        minutes: part1_minutes,
+        elephant_minutes,
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    /// Both parts read off the same value function, so it is solved up front.
+    type Input = ValueFunction;
+
+    const PARAMS: &'static [Param] = &[MINUTES, ELEPHANT_MINUTES];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        value_function(input)
+    }
+
+    fn part1(value: &Self::Input) -> Answer {
+        part1(value).into()
+    }
+
+    fn part2(value: &Self::Input) -> Option<Answer> {
+        Some(part2(value).into())
+    }
+}
+
+pub fn part1(value: &ValueFunction) -> usize {
+    value.opt[(value.minutes - 1, value.aa_idx, value.mm - 1)]
+}
+
+pub fn part2(value: &ValueFunction) -> usize {
+    let ValueFunction {
+        opt, aa_idx, mm, ..
+    } = value;
+    let (aa_idx, mm) = (*aa_idx, *mm);
+    // enumerate all possible ways of dividing the set of valves into two
+    // then solve the dp problem for me and my elephant with 26 minutes remaining from every
+    // possible divison of the set
+    let t = value.elephant_minutes - 1;
+
+    // we can restrict the sets "my_valves" and "elephant_valves" by treating some of the valves
+    // as already open (bitmask set to 0) in my initial state, and likewise for the elephant
+    let divisions: Vec<usize> = (0..mm / 2).collect();
+    let results = par_map(&divisions, |&my_valves| {
+        let elephant_valves = mm - 1 - my_valves;
+        opt[(t, aa_idx, my_valves)] + opt[(t, aa_idx, elephant_valves)]
+    });
+    results.into_iter().max().unwrap_or(0)
+}
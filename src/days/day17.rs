@@ -0,0 +1,385 @@
+use crate::error::{char_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::stepper::{Simulation, Steppable, Stepper};
+use crate::util::cycle;
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::util::render::{Picture, Rgb, Style};
+use crate::{debug, trace};
+
+#[derive(Clone)]
+pub enum Move {
+    Left,
+    Right,
+}
+
+struct Piece {
+    rocks: Vec<(i32, i32)>,
+    height: usize,
+}
+
+/// Add/remove empty rows to/from state to ensure that piece is inserted at the correct position
+/// (bottom left corner of piece bounding box three columns from left edge, three rows from
+/// uppermost rock in state). The floor levels are shifted to match.
+fn resize_for_piece(state: &mut Grid<usize>, floor_level: &mut [usize], piece: &Piece) {
+    let mut max_diff: i32 = -1000;
+    for level in floor_level.iter() {
+        let diff: i32 = piece.height as i32 + 3 - *level as i32;
+        if diff > max_diff {
+            max_diff = diff;
+        }
+    }
+
+    if max_diff > 0 {
+        // need to add head room
+        state.insert_rows(0, max_diff as usize, 0);
+    } else if max_diff < 0 {
+        // need to remove head room
+        state.remove_rows(0..-max_diff as usize);
+    }
+    for level in floor_level.iter_mut() {
+        *level = (*level as i32 + max_diff) as usize;
+    }
+}
+
+/// Given a state, a piece, and a position, check if any of the rocks in the piece intersect
+/// with any of the rocks in the state (and return true), else false.
+fn check_for_collision(state: &Grid<usize>, piece: &Piece, piece_pos: (usize, usize)) -> bool {
+    for rock in piece.rocks.iter() {
+        let rock_pos = (piece_pos.0 as i32 + rock.0, piece_pos.1 as i32 + rock.1);
+        // check move puts us within bounds
+        if rock_pos.0 < 0 || rock_pos.0 > 6 {
+            return true; // collided with boundary
+        }
+        if rock_pos.1 < 0 {
+            // should never have been able to move upwards???
+            unreachable!()
+        }
+
+        if state[(rock_pos.1 as usize, rock_pos.0 as usize)] == 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// We will assume piece_pos is given such that casting rock_pos to usize and indexing state will
+/// not cause an indexing error. This is a possible source of runtime panics. The floor level of
+/// each column the piece covers is raised to its highest rock.
+fn add_piece_to_state(
+    state: &mut Grid<usize>,
+    floor_level: &mut [usize],
+    piece: &Piece,
+    piece_pos: (usize, usize),
+) {
+    for rock in piece.rocks.iter() {
+        let rock_pos = (piece_pos.0 as i32 + rock.0, piece_pos.1 as i32 + rock.1);
+        let (y, x) = (rock_pos.1 as usize, rock_pos.0 as usize);
+        state[(y, x)] = 1;
+        floor_level[x] = floor_level[x].min(y);
+    }
+}
+
+fn drop_piece(
+    moves: &[Move],
+    state: &mut Grid<usize>,
+    floor_level: &mut [usize],
+    curr_piece: &Piece,
+    init_counter: usize,
+) -> usize {
+    let mut counter = init_counter;
+    let mut piece_pos = (2, curr_piece.height - 1);
+    loop {
+        if counter.is_multiple_of(2) {
+            // blow
+            let curr_move = &moves[(counter / 2) % moves.len()];
+            match curr_move {
+                Move::Left => {
+                    if piece_pos.0 == 0 {
+                        trace!("Jet pushes rock left, but nothing happens");
+                    } else if !check_for_collision(
+                        state,
+                        curr_piece,
+                        (piece_pos.0 - 1, piece_pos.1),
+                    ) {
+                        piece_pos.0 -= 1;
+                        trace!("Jet pushes rock left");
+                    } else {
+                        trace!("Jet pushes rock left, but nothing happens");
+                    }
+                }
+                Move::Right => {
+                    if !check_for_collision(state, curr_piece, (piece_pos.0 + 1, piece_pos.1)) {
+                        piece_pos.0 += 1;
+                        trace!("Jet pushes rock right");
+                    } else {
+                        trace!("Jet pushes rock right, but nothing happens");
+                    }
+                }
+            };
+        } else {
+            // drop
+            if !check_for_collision(state, curr_piece, (piece_pos.0, piece_pos.1 + 1)) {
+                trace!("Rock falls 1 unit");
+                piece_pos.1 += 1;
+            } else {
+                add_piece_to_state(state, floor_level, curr_piece, piece_pos);
+                break;
+            }
+        }
+        counter += 1;
+    }
+    counter
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    /// The jet pattern.
+    type Input = Vec<Move>;
+
+    const PARAMS: &'static [Param] = &[ROCKS, MANY_ROCKS];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let jets = input::trim(input);
+        if jets.is_empty() {
+            return Err(AocError::at_end(input, "a jet pattern"));
+        }
+        jets.char_indices()
+            .map(|(i, x)| match x {
+                '>' => Ok(Move::Right),
+                '<' => Ok(Move::Left),
+                _ => Err(AocError::at(input, char_at(jets, i), "`<` or `>`")),
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        part1(moves).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Option<Answer> {
+        Some(part2(moves).into())
+    }
+
+    /// Rocks falling into the chamber, one at a time.
+    fn simulate(moves: &Self::Input) -> Option<Box<dyn Steppable>> {
+        Some(Box::new(Stepper::new(Falling {
+            moves: moves.clone(),
+            pieces: pieces(),
+        })))
+    }
+
+    /// The tower after the rocks of part 1.
+    fn render(moves: &Self::Input) -> Option<Picture> {
+        let pieces = pieces();
+        let mut tower = Tower::new();
+        for _ in 0..ROCKS.get() {
+            tower.drop_rock(moves, &pieces);
+        }
+        Some(tower.draw())
+    }
+}
+
+/// The chamber after some number of rocks have come to rest.
+#[derive(Clone)]
+struct Tower {
+    /// state[(y, x)] = 1 if there is a rock, 0 otherwise. Our y coordinate system will be
+    /// increasing downwards, with the zero level at the current rock insertion point
+    state: Grid<usize>,
+    /// The y-index of the highest rock in each column
+    floor_level: Vec<usize>,
+    /// Clock alternating between blowing (even) and dropping (odd) the falling rock
+    counter: usize,
+    /// Rocks dropped so far
+    rocks: usize,
+}
+
+impl Tower {
+    fn new() -> Tower {
+        let floor_level: Vec<usize> = vec![3; 7];
+        let mut state = Grid::new(floor_level[0], floor_level.len(), 0);
+        state.insert_rows(floor_level[0], 1, 1); // add the floor
+        Tower {
+            state,
+            floor_level,
+            counter: 0,
+            rocks: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.state.height() - 1 - self.floor_level.iter().min().unwrap()
+    }
+
+    /// The chamber from the top of the tower down to the floor, as the puzzle draws it.
+    fn draw(&self) -> Picture {
+        let top = *self.floor_level.iter().min().unwrap();
+        let (height, width) = (self.state.height() - top, self.state.width() + 2);
+        Picture::from_fn(height, width, |(row, col)| {
+            let wall = col == 0 || col == width - 1;
+            if row == height - 1 {
+                Style::new(if wall { '+' } else { '-' }, Rgb::GREY)
+            } else if wall {
+                Style::new('|', Rgb::GREY)
+            } else if self.state[(top + row, col - 1)] == 1 {
+                Style::new('#', Rgb::BROWN)
+            } else {
+                Style::new('.', Rgb::DARK)
+            }
+        })
+    }
+
+    /// Drop the next rock and let it come to rest.
+    fn drop_rock(&mut self, moves: &[Move], pieces: &[Piece]) {
+        let curr_piece = &pieces[self.rocks % pieces.len()];
+        // add or remove headroom (alternatively viewed, set y=0 to the right place) for size
+        // of piece
+        resize_for_piece(&mut self.state, &mut self.floor_level, curr_piece);
+        // tetris the piece according to the movelist, keeping the list of highest rock in each
+        // column, used for setting the y=0 level appropriately, up to date as it lands
+        self.counter = drop_piece(
+            moves,
+            &mut self.state,
+            &mut self.floor_level,
+            curr_piece,
+            self.counter,
+        );
+        // if the clock is on a 'drop' phase, go to a 'blow' phase, as all pieces are blown to
+        // begin with
+        if self.counter % 2 == 1 {
+            self.counter += 1;
+        }
+        self.rocks += 1;
+    }
+
+    /// Define our metastate by the depth of each column below the top of the tower, where we are
+    /// in the piece cycle, and where we are in the jet cycle. The column profile can't see
+    /// overhangs, so this is vulnerable to adversarial inputs, but it works for the (thankfully
+    /// tame) input given by advent of code
+    fn metastate(&self, n_pieces: usize, n_moves: usize) -> ([usize; 7], usize, usize) {
+        let top = *self.floor_level.iter().min().unwrap();
+        let mut profile = [0; 7];
+        for (depth, level) in profile.iter_mut().zip(&self.floor_level) {
+            *depth = level - top;
+        }
+        (profile, self.rocks % n_pieces, (self.counter / 2) % n_moves)
+    }
+}
+
+/// Rocks dropping into the chamber, a step for each one to come to rest.
+struct Falling {
+    moves: Vec<Move>,
+    pieces: [Piece; 5],
+}
+
+impl Simulation for Falling {
+    type State = Tower;
+
+    fn start(&self) -> Tower {
+        Tower::new()
+    }
+
+    fn step(&self, tower: &Tower) -> Option<Tower> {
+        let mut tower = tower.clone();
+        tower.drop_rock(&self.moves, &self.pieces);
+        Some(tower)
+    }
+
+    fn draw(&self, tower: &Tower) -> Picture {
+        tower.draw()
+    }
+
+    fn values(&self, tower: &Tower) -> Vec<(&'static str, i64)> {
+        vec![
+            ("rocks", tower.rocks as i64),
+            ("height", tower.height() as i64),
+        ]
+    }
+}
+
+/// The rocks, in the order they fall, each as the squares it covers relative to its bottom left
+/// corner.
+fn pieces() -> [Piece; 5] {
+    [
+        Piece {
+            rocks: vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+            height: 1,
+        },
+        Piece {
+            rocks: vec![(1, 0), (0, -1), (1, -1), (2, -1), (1, -2)],
+            height: 3,
+        },
+        Piece {
+            rocks: vec![(0, 0), (1, 0), (2, 0), (2, -1), (2, -2)],
+            height: 3,
+        },
+        Piece {
+            rocks: vec![(0, 0), (0, -1), (0, -2), (0, -3)],
+            height: 4,
+        },
+        Piece {
+            rocks: vec![(0, 0), (1, 0), (0, -1), (1, -1)],
+            height: 2,
+        },
+    ]
+}
+
+const ROCKS: Param = Param {
+    day: 17,
+    name: "rocks",
+    default: 2022,
+    range: 0..=i64::MAX,
+    help: "the rocks to drop in part 1",
+};
+
+const MANY_ROCKS: Param = Param {
+    day: 17,
+    name: "many_rocks",
+    default: 1000000000000,
+    range: 0..=i64::MAX,
+    help: "the rocks to drop in part 2",
+};
+
+/// The height of the tower after 2022 rocks.
+pub fn part1(moves: &[Move]) -> usize {
+    tower_height(moves, ROCKS.get())
+}
+
+/// The height of the tower after a trillion rocks.
+pub fn part2(moves: &[Move]) -> usize {
+    tower_height(moves, MANY_ROCKS.get())
+}
+
+/// Drop `n_rocks` rocks into the chamber and return the height of the resulting tower. Once the
+/// falling rocks settle into a cycle, the height is extrapolated out to `n_rocks`.
+fn tower_height(moves: &[Move], n_rocks: usize) -> usize {
+    let pieces = pieces();
+    let mut tower = Tower::new();
+    // store height of the tower after each rock to do the cycle-height arithmetic later
+    let mut heights: Vec<usize> = vec![0];
+    let cycle = cycle::find_cycle(
+        &mut tower,
+        n_rocks,
+        |tower| {
+            tower.drop_rock(moves, &pieces);
+            heights.push(tower.height());
+        },
+        |tower| tower.metastate(pieces.len(), moves.len()),
+    );
+
+    match cycle {
+        Some(cycle) => {
+            debug!(
+                "Found cycle beginning at {}, with length {} and height {}",
+                cycle.start,
+                cycle.length,
+                heights[cycle.start + cycle.length] - heights[cycle.start]
+            );
+            cycle.extrapolate(&heights, n_rocks)
+        }
+        None => heights[n_rocks],
+    }
+}
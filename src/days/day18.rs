@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::util::search;
+
+/// upper bound on the extent of the droplet, found by eye but easily automated
+static EXTENT: usize = 32;
+
+fn coord_to_idx(x: usize, y: usize, z: usize) -> usize {
+    z * EXTENT * EXTENT + y * EXTENT + x
+}
+
+/// Given the coordinate of a cube, check if occupied contains a cube at each neighbouring coord
+/// and return the number of open faces
+fn open_faces(occupied: &[usize], idx: usize) -> usize {
+    let mut open: usize = 6;
+
+    if occupied.contains(&(idx + 1)) {
+        open -= 1;
+    }
+    if idx > 0 && occupied.contains(&(idx - 1)) {
+        open -= 1;
+    }
+    if occupied.contains(&(idx + EXTENT)) {
+        open -= 1;
+    }
+    if idx >= EXTENT && occupied.contains(&(idx - EXTENT)) {
+        open -= 1;
+    }
+    if occupied.contains(&(idx + EXTENT * EXTENT)) {
+        open -= 1;
+    }
+    if idx >= EXTENT * EXTENT && occupied.contains(&(idx - EXTENT * EXTENT)) {
+        open -= 1;
+    }
+    open
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    /// Index of every cube of lava.
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_cubes(input)
+    }
+
+    fn part1(occupied: &Self::Input) -> Answer {
+        part1(occupied).into()
+    }
+
+    fn part2(occupied: &Self::Input) -> Option<Answer> {
+        Some(part2(occupied).into())
+    }
+}
+
+fn read_cubes(input: &str) -> Result<Vec<usize>> {
+    input::lines(input)
+        .iter()
+        .map(|x| {
+            let coord = x
+                .split(',')
+                .map(|y| match parse_at::<usize>(input, y, "a coordinate")? {
+                    // shifted along one, so that there is always air around the droplet to search
+                    // through in part 2, even next to coordinate 0
+                    c if c + 2 < EXTENT => Ok(c + 1),
+                    _ => Err(AocError::at(
+                        input,
+                        y,
+                        format!("a coordinate below {}", EXTENT - 2),
+                    )),
+                })
+                .collect::<Result<Vec<usize>>>()?;
+            match coord[..] {
+                [x, y, z] => Ok(coord_to_idx(x, y, z)),
+                _ => Err(AocError::at(input, x, "three coordinates like 2,2,2")),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(occupied: &[usize]) -> usize {
+    let mut total_open_faces = 0;
+    for cube in occupied.iter() {
+        total_open_faces += open_faces(occupied, *cube);
+    }
+
+    total_open_faces
+}
+
+pub fn part2(occupied: &[usize]) -> usize {
+    let lava: HashSet<usize> = occupied.iter().copied().collect();
+    // find every point of air accessible from the corner (0, 0, 0) using bfs
+    let outside = search::bfs(
+        coord_to_idx(0, 0, 0),
+        |&idx| {
+            neighbours(idx)
+                .into_iter()
+                .filter(|next| !lava.contains(next))
+                .collect::<Vec<_>>()
+        },
+        |_| false,
+    );
+    // every face of lava touching one of those points is on the outer surface
+    outside
+        .distances()
+        .keys()
+        .map(|&idx| {
+            neighbours(idx)
+                .iter()
+                .filter(|next| lava.contains(next))
+                .count()
+        })
+        .sum()
+}
+
+/// Indices of the up to six orthogonal neighbours of `idx` that are inside the extent.
+fn neighbours(idx: usize) -> Vec<usize> {
+    let (x, y, z) = (idx % EXTENT, idx / EXTENT % EXTENT, idx / (EXTENT * EXTENT));
+    let mut neighbours = vec![];
+    for (dx, dy, dz) in [(1, 0, 0), (0, 1, 0), (0, 0, 1)] {
+        if x + dx < EXTENT && y + dy < EXTENT && z + dz < EXTENT {
+            neighbours.push(coord_to_idx(x + dx, y + dy, z + dz));
+        }
+        if x >= dx && y >= dy && z >= dz {
+            neighbours.push(coord_to_idx(x - dx, y - dy, z - dz));
+        }
+    }
+    neighbours
+}
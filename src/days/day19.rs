@@ -0,0 +1,275 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+
+use crate::debug;
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::util::par::par_map;
+
+#[derive(Debug)]
+pub struct Factory {
+    ore_cost: usize,
+    clay_cost: usize,
+    obby_cost: (usize, usize),
+    geod_cost: (usize, usize),
+}
+
+/// Assuming we create a geobot once a minute every minute for the remaining time, how many
+/// more geodes can we get?
+///
+/// mr = curr_geobots * time_remaining + (curr_geobots+1)*(time_remaining-1)...
+///
+/// yes, i know i can figure out an analytical expression for this. no i can't be bothered to
+/// do that after a bottle of mulled wine.
+fn max_reward(time_remaining: usize, current_geobots: usize) -> usize {
+    let mut sum = 0;
+    for i in 0..time_remaining {
+        sum += (current_geobots + i) * (time_remaining - i);
+    }
+    sum
+}
+
+fn read_factories(input: &str) -> Result<Vec<Factory>> {
+    lazy_static! {
+        static ref ROBOT_RE: Regex = Regex::new(r"Blueprint (\d+): .+(\d+) ore\..+(\d+) ore\..+(\d+) ore and (\d+) clay\..+(\d+) ore and (\d+) obsidian\.").unwrap();
+    }
+    input::lines(input)
+        .iter()
+        .map(|line| {
+            let x = ROBOT_RE
+                .captures(line)
+                .ok_or_else(|| AocError::at(input, line, "a blueprint"))?;
+            let cost = |i| parse_at::<usize>(input, x.get(i).unwrap().as_str(), "a cost");
+            Ok(Factory {
+                ore_cost: cost(2)?,
+                clay_cost: cost(3)?,
+                obby_cost: (cost(4)?, cost(5)?),
+                geod_cost: (cost(6)?, cost(7)?),
+            })
+        })
+        .collect()
+}
+
+/// time, orebots, claybots, obbybots, geobots, ore, clay, obby
+pub type State = (usize, usize, usize, usize, usize, usize, usize, usize);
+
+/// I was a bit lazy with this, so my code is quite sloppy. If I wrote it again I would refactor
+/// the build orders into an enum of robot types and a static lookup table of their costs
+/// and the effect on the resources so I
+/// could just issue all the build orders with one function, and have all the data in one place.
+///
+/// Building in release mode this is actually not too shabby on the runtime front, much better
+/// than I initially expected considering the size of the state space! This required quite a lot
+/// of pruning techniques that I'm not ashamed to admit I found on r/adventofcode. These are
+///
+/// 1) discard states that can't possibly exceed the best state seen so far even with an unphysical
+///    amount of geode bot production
+/// 2) avoid repeatedly entering the same state from different branches (although I feel like this
+///    isn't possible, but I haven't thought about it long enough)
+/// 3) never build more robots than the maximum possible required production of that resource per
+///    minute
+/// 4) order the insertion of states into the queue so that (1) is likely to prune a lot of useless
+///    states where we do nothing for long periods of time.
+/// 5) if a geobot can be built, assume this is optimal and do not queue any sibling states
+fn max_geodes(factory: &Factory, minutes: usize) -> usize {
+    debug!("{:?}", factory);
+    // for each factory we can do a simple dfs over states to find their max reward
+    let init_state: State = (0, 1, 0, 0, 0, 0, 0, 0);
+
+    let max_ore_cost = factory
+        .ore_cost
+        .max(factory.clay_cost)
+        .max(factory.obby_cost.0)
+        .max(factory.geod_cost.0);
+
+    // state = time, orebots, claybots, obbybots, geobots, ore, clay, obby
+    // state_rewards maps state to number of geodes
+    let mut state_rewards: HashMap<State, usize> = HashMap::new();
+    state_rewards.insert(init_state, 0);
+
+    // dfs
+    let mut queue: VecDeque<State> = VecDeque::new();
+    queue.push_front(init_state);
+    let mut maxval = 0;
+    while let Some(state) = queue.pop_front() {
+        if state.0 == minutes {
+            // time is up, or we've been here before, do not advance this state
+            continue;
+        }
+        let curr_reward = state_rewards[&state];
+
+        let future_max_reward = curr_reward + max_reward(minutes - state.0, state.4);
+
+        if future_max_reward < maxval {
+            // we can't possibly beat the current best state with this, skip!
+            continue;
+        }
+
+        let next_reward = state.4 + state_rewards[&state];
+        if next_reward > maxval {
+            maxval = next_reward;
+        }
+
+        // build geobot
+        if state.5 >= factory.geod_cost.0 && state.7 >= factory.geod_cost.1 {
+            let next_state = (
+                state.0 + 1, // increment time
+                state.1,     // orebots stays same
+                state.2,     // claybots stays same
+                state.3,     // obbybots stays same
+                state.4 + 1, // increment geobots
+                state.5 - factory.geod_cost.0 + state.1,
+                state.6 + state.2,
+                state.7 - factory.geod_cost.1 + state.3,
+            );
+
+            if let Entry::Vacant(entry) = state_rewards.entry(next_state) {
+                queue.push_front(next_state);
+                entry.insert(next_reward);
+                // if we built a geobot, consider this an optimal move and dont push any other
+                // choices onto the search
+                continue;
+            }
+        }
+        let next_state = (
+            state.0 + 1, // increment time
+            state.1,     // orebots stays same
+            state.2,     // claybots stays same
+            state.3,     // obbybots stays same
+            state.4,     // geobots stays same
+            state.5 + state.1,
+            state.6 + state.2,
+            state.7 + state.3,
+        );
+        if let Entry::Vacant(entry) = state_rewards.entry(next_state) {
+            queue.push_front(next_state);
+            entry.insert(next_reward);
+        }
+
+        // build orebot
+        if state.5 >= factory.ore_cost && state.1 < max_ore_cost {
+            let next_state = (
+                state.0 + 1,                          // increment time
+                state.1 + 1,                          // increment orebots
+                state.2,                              // claybots stays same
+                state.3,                              // obbybots stays same
+                state.4,                              // geobots stays same
+                state.5 - factory.ore_cost + state.1, // spend ore and gather ore
+                state.6 + state.2,
+                state.7 + state.3,
+            );
+            if let Entry::Vacant(entry) = state_rewards.entry(next_state) {
+                queue.push_front(next_state);
+                entry.insert(next_reward);
+            }
+        }
+        // build claybot, unless we already farm more than max clay cost per minute
+        if state.5 >= factory.clay_cost && state.2 < factory.obby_cost.1 {
+            let next_state = (
+                state.0 + 1, // increment time
+                state.1,     // orebots stays same
+                state.2 + 1, // increment claybots
+                state.3,     // obbybots stays same
+                state.4,     // geobots stays same
+                state.5 - factory.clay_cost + state.1,
+                state.6 + state.2,
+                state.7 + state.3,
+            );
+            if let Entry::Vacant(entry) = state_rewards.entry(next_state) {
+                queue.push_front(next_state);
+                entry.insert(next_reward);
+            }
+        }
+        // build obbybot, unless we already farm more than max obby cost per minute
+        if state.5 >= factory.obby_cost.0
+            && state.6 >= factory.obby_cost.1
+            && state.3 < factory.geod_cost.1
+        {
+            let next_state = (
+                state.0 + 1, // increment time
+                state.1,     // orebots stays same
+                state.2,     // claybots stays same
+                state.3 + 1, // increment obbybots
+                state.4,     // geobots stays same
+                state.5 - factory.obby_cost.0 + state.1,
+                state.6 - factory.obby_cost.1 + state.2,
+                state.7 + state.3,
+            );
+            if let Entry::Vacant(entry) = state_rewards.entry(next_state) {
+                queue.push_front(next_state);
+                entry.insert(next_reward);
+            }
+        }
+    }
+
+    debug!("Opens at most {} geodes", maxval);
+    maxval
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    /// Every blueprint, in order.
+    type Input = Vec<Factory>;
+
+    const PARAMS: &'static [Param] = &[MINUTES, LONG_MINUTES, BLUEPRINTS];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_factories(input)
+    }
+
+    fn part1(factories: &Self::Input) -> Answer {
+        part1(factories).into()
+    }
+
+    fn part2(factories: &Self::Input) -> Option<Answer> {
+        Some(part2(factories).into())
+    }
+}
+
+const MINUTES: Param = Param {
+    day: 19,
+    name: "minutes",
+    default: 24,
+    range: 0..=i64::MAX,
+    help: "the minutes to open geodes for in part 1",
+};
+
+const LONG_MINUTES: Param = Param {
+    day: 19,
+    name: "long_minutes",
+    default: 32,
+    range: 0..=i64::MAX,
+    help: "the minutes to open geodes for in part 2",
+};
+
+const BLUEPRINTS: Param = Param {
+    day: 19,
+    name: "blueprints",
+    default: 3,
+    range: 0..=i64::MAX,
+    help: "the blueprints that are left in part 2",
+};
+
+// Each blueprint is searched separately, so they are shared out between threads.
+
+pub fn part1(factories: &[Factory]) -> usize {
+    let minutes = MINUTES.get();
+    let geodes = par_map(factories, |factory| max_geodes(factory, minutes));
+    let quality_levels = geodes
+        .iter()
+        .enumerate()
+        .map(|(i, geodes)| (i + 1) * geodes);
+    quality_levels.sum::<usize>()
+}
+
+pub fn part2(factories: &[Factory]) -> usize {
+    let factories = &factories[..factories.len().min(BLUEPRINTS.get())];
+    let minutes = LONG_MINUTES.get();
+    let geodes = par_map(factories, |factory| max_geodes(factory, minutes));
+    geodes.iter().product::<usize>()
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::error::AocError;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+
+fn rps_score(enemy_move: usize, player_move: usize) -> usize {
+    if enemy_move == player_move {
+        return 3;
+    } else {
+        if enemy_move == 1 {
+            // rock
+            if player_move == 2 {
+                // vs paper
+                return 6;
+            } else if player_move == 3 {
+                // vs scissors
+                return 0;
+            }
+        } else if enemy_move == 2 {
+            // paper
+            if player_move == 1 {
+                // vs rock
+                return 0;
+            } else if player_move == 3 {
+                // vs scissors
+                return 6;
+            }
+        } else if enemy_move == 3 {
+            // scissors
+            if player_move == 1 {
+                // vs rock
+                return 6;
+            } else if player_move == 2 {
+                // vs paper
+                return 0;
+            }
+        }
+    }
+    0
+}
+
+fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<usize>)> {
+    let enemy: HashMap<&str, usize> = HashMap::from([("A", 1), ("B", 2), ("C", 3)]);
+    let player: HashMap<&str, usize> = HashMap::from([("X", 1), ("Y", 2), ("Z", 3)]);
+    let mut enemy_moves = vec![];
+    let mut player_moves = vec![];
+    for line in input::lines(input) {
+        let (a, b) = line
+            .split_once(' ')
+            .ok_or_else(|| AocError::at(input, line, "two moves separated by a space"))?;
+        enemy_moves.push(
+            *enemy
+                .get(a)
+                .ok_or_else(|| AocError::at(input, a, "A, B or C"))?,
+        );
+        player_moves.push(
+            *player
+                .get(b)
+                .ok_or_else(|| AocError::at(input, b, "X, Y or Z"))?,
+        );
+    }
+    Ok((enemy_moves, player_moves))
+}
+
+/// Calculate our score under the false assumption that the strategy guide
+/// means X = rock, Y = paper, Z = scissors
+fn calculate_score(enemy_moves: &[usize], player_moves: &[usize]) -> usize {
+    let mut score = 0;
+    for round in enemy_moves.iter().zip(player_moves.iter()) {
+        score += round.1;
+        score += rps_score(*round.0, *round.1);
+    }
+    score
+}
+
+/// Calculate our score under the correct strategy
+/// X = lose, Y = draw, Z = win
+fn calculate_strategy_score(enemy_moves: &[usize], player_moves: &[usize]) -> usize {
+    let strategy = HashMap::from([
+        ((1, 1), 3),     // lose + scissors
+        ((1, 2), 3 + 1), // draw + rock
+        ((1, 3), 6 + 2), // win + paper
+        ((2, 1), 1),     // lose + rock
+        ((2, 2), 3 + 2), // draw + paper
+        ((2, 3), 6 + 3), // win + scissors
+        ((3, 1), 2),     // lose + paper
+        ((3, 2), 3 + 3), // draw + scissors
+        ((3, 3), 6 + 1), // win + rock
+    ]);
+    let mut score = 0;
+    for round in enemy_moves.iter().zip(player_moves.iter()) {
+        score += strategy.get(&(*round.0, *round.1)).unwrap();
+    }
+    score
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The enemy's moves and our responses, 1 = rock/X, 2 = paper/Y, 3 = scissors/Z.
+    type Input = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(parsed_in: &Self::Input) -> Answer {
+        part1(parsed_in).into()
+    }
+
+    fn part2(parsed_in: &Self::Input) -> Option<Answer> {
+        Some(part2(parsed_in).into())
+    }
+}
+
+/// The score from playing the second column as our moves.
+pub fn part1((enemy_moves, player_moves): &(Vec<usize>, Vec<usize>)) -> usize {
+    calculate_score(enemy_moves, player_moves)
+}
+
+/// The score from playing to the outcome the second column asks for.
+pub fn part2((enemy_moves, player_moves): &(Vec<usize>, Vec<usize>)) -> usize {
+    calculate_strategy_score(enemy_moves, player_moves)
+}
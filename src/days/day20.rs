@@ -0,0 +1,114 @@
+use crate::debug;
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::{checked, input};
+
+#[derive(Clone)]
+struct Item {
+    pos: usize,
+    val: i64,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    /// The encrypted file.
+    type Input = Vec<i64>;
+
+    const PARAMS: &'static [Param] = &[KEY, ROUNDS];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let file: Vec<i64> = input::lines(input)
+            .iter()
+            .map(|x| parse_at(input, x, "an integer"))
+            .collect::<Result<_>>()?;
+        if !file.contains(&0) {
+            return Err(AocError::at_end(
+                input,
+                "a 0 to find the grove coordinates from",
+            ));
+        }
+        Ok(file)
+    }
+
+    fn part1(file: &Self::Input) -> Answer {
+        part1(file).into()
+    }
+
+    fn part2(file: &Self::Input) -> Option<Answer> {
+        Some(part2(file).into())
+    }
+}
+
+const KEY: Param = Param {
+    day: 20,
+    name: "key",
+    default: 811589153,
+    range: i64::MIN..=i64::MAX,
+    help: "the decryption key every number is multiplied by in part 2",
+};
+
+const ROUNDS: Param = Param {
+    day: 20,
+    name: "rounds",
+    default: 10,
+    range: 0..=i64::MAX,
+    help: "the times the file is mixed in part 2",
+};
+
+/// The grove coordinates after mixing the file once.
+pub fn part1(file: &[i64]) -> i64 {
+    mix(file, 1, 1)
+}
+
+/// The grove coordinates after applying the decryption key and mixing the file ten times.
+pub fn part2(file: &[i64]) -> i64 {
+    mix(file, KEY.get(), ROUNDS.get())
+}
+
+/// Multiply every number in the file by `key`, then mix the file `rounds` times and return the
+/// sum of the grove coordinates.
+fn mix(file: &[i64], key: i64, rounds: usize) -> i64 {
+    let mut shift_list: Vec<Item> = file
+        .iter()
+        .enumerate()
+        .map(|(i, x)| Item {
+            pos: i,
+            val: checked::mul(*x, key),
+        })
+        .collect();
+    let n = shift_list.len();
+
+    for _ in 0..rounds {
+        for i in 0..n {
+            let old_pos = shift_list.iter().position(|x| x.pos == i).unwrap();
+            let item = shift_list[old_pos].clone();
+            if item.val == 0 {
+                continue;
+            }
+            // Today I learned the remainder and modulo operations are different for negative
+            // numbers
+            let new_pos = checked::add(old_pos as i64, item.val).rem_euclid(n as i64 - 1);
+            let new_pos = new_pos as usize;
+            shift_list.remove(old_pos);
+            shift_list.insert(new_pos, item);
+        }
+    }
+
+    // find zero
+    let zero_pos = shift_list.iter().position(|x| x.val == 0).unwrap();
+    let thou_pos = (zero_pos + 1000) % n;
+    let twothou_pos = (zero_pos + 2000) % n;
+    let threethou_pos = (zero_pos + 3000) % n;
+
+    debug!(
+        "Grove coordinates are {}, {} and {}",
+        shift_list[thou_pos].val, shift_list[twothou_pos].val, shift_list[threethou_pos].val
+    );
+    [twothou_pos, threethou_pos]
+        .iter()
+        .fold(shift_list[thou_pos].val, |sum, &pos| {
+            checked::add(sum, shift_list[pos].val)
+        })
+}
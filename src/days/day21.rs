@@ -0,0 +1,355 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::debug;
+use crate::error::{parse_at, AocError};
+use crate::repl::Query;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::bigint::{self, BigInt, Integer};
+use crate::util::input;
+
+/// What a monkey does with the numbers it waits on.
+#[derive(Debug)]
+pub enum Op {
+    Add,
+    Mul,
+    Sub,
+    Div,
+}
+
+/// A monkey's job: yell a number, or yell the result of an operation on two other monkeys.
+#[derive(Debug)]
+pub enum Operand {
+    Num(isize),
+    Calc(Op, String, String),
+}
+
+/// Every monkey's job, by name.
+pub type Operands = HashMap<String, Operand>;
+
+// recursively evaluates operands
+fn eval_op<N: Integer>(operand: &Operand, operands: &Operands) -> N {
+    match operand {
+        Operand::Num(val) => N::from_i64(*val as i64),
+        Operand::Calc(op, lhs, rhs) => {
+            let lhs_val: N = eval_op(&operands[lhs], operands);
+            let rhs_val = eval_op(&operands[rhs], operands);
+            match op {
+                Op::Add => lhs_val.add(&rhs_val),
+                Op::Mul => lhs_val.mul(&rhs_val),
+                Op::Sub => lhs_val.sub(&rhs_val),
+                Op::Div => lhs_val.div(&rhs_val),
+            }
+        }
+    }
+}
+
+// return path from this node to human
+fn node_to_humn<'a>(
+    operand: &'a Operand,
+    operands: &'a Operands,
+    name: &'a str,
+) -> Option<Vec<&'a str>> {
+    let mut result: Vec<&str> = vec![name];
+    match operand {
+        Operand::Num(_) => return None,
+        Operand::Calc(_, lhs, rhs) => {
+            if lhs == "humn" || rhs == "humn" {
+                return Some(vec![name, "humn"]);
+            }
+
+            let lhs_op = &operands[lhs];
+            let rhs_op = &operands[rhs];
+
+            if let Some(v) = node_to_humn(lhs_op, operands, lhs) {
+                result.extend(v);
+            } else if let Some(v) = node_to_humn(rhs_op, operands, rhs) {
+                result.extend(v);
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(result)
+}
+
+/// Starting from root, we know what the inverse calculation tracing the path from root to human
+/// has to be equal to. At each step on the path, we can directly evaluate the child branch that
+/// does not go to human, and then enact the inverse operation on the 'result' we want until
+/// we reach human, at which point we will be left with the appropriate value that yields result
+/// when the tree is evaluated in forward mode. Returns `None` if the path leaves no such value.
+fn eval_op_inverse<N: Integer>(
+    operand: &Operand,
+    operands: &Operands,
+    humn_path: &mut Vec<&str>,
+    res: N,
+    name: &str,
+) -> Option<N> {
+    if name == "humn" {
+        return Some(res);
+    }
+
+    let (op, lhs, rhs) = match operand {
+        Operand::Calc(op, lhs, rhs) => (op, lhs, rhs),
+        Operand::Num(_) => return None,
+    };
+    let humn_left = *lhs == humn_path.pop()?;
+    let (to_solve, other) = if humn_left {
+        // lhs("humn") . rhs = res  => lhs("humn") = res /. rhs
+        // lhs("humn") - rhs = res => lhs("humn") = res + rhs
+        // lhs("humn") + rhs = res => lhs("humn") = res - rhs
+        // lhs("humn") * rhs = res => lhs("humn") = res / rhs
+        // lhs("humn") / rhs = res => lhs("humn") = rhs * res
+        (lhs, eval_op(&operands[rhs], operands))
+    } else {
+        // lhs . rhs("humn") = res  =>   rhs("humn") = lhs /. res
+        // lhs - rhs("humn") = res => rhs("humn") = lhs - res
+        // lhs + rhs("humn") = res => rhs("humn") = res - rhs
+        // lhs * rhs("humn") = res => rhs("humn") = res / lhs
+        // lhs / rhs("humn") = res => rhs("humn") = lhs / res
+        (rhs, eval_op(&operands[lhs], operands))
+    };
+
+    // a product or quotient of zero leaves nothing to divide by, and no number humn could yell
+    let zero = N::from_i64(0);
+    let solved = match (op, humn_left) {
+        (Op::Add, _) => res.sub(&other),
+        (Op::Mul, _) if other == zero => return None,
+        (Op::Mul, _) => res.div(&other),
+        (Op::Sub, false) => other.sub(&res),
+        (Op::Div, false) if res == zero => return None,
+        (Op::Div, false) => other.div(&res),
+        (Op::Sub, true) => other.add(&res),
+        (Op::Div, true) => other.mul(&res),
+    };
+    eval_op_inverse(&operands[to_solve], operands, humn_path, solved, to_solve)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Operands;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = input::lines(input);
+        check_monkeys(input, &lines)?;
+        Ok(parse_operands(&lines))
+    }
+
+    fn part1(operands: &Self::Input) -> Answer {
+        if bigint::exact() {
+            part1::<BigInt>(operands).into()
+        } else {
+            part1::<isize>(operands).into()
+        }
+    }
+
+    fn part2(operands: &Self::Input) -> Option<Answer> {
+        if bigint::exact() {
+            part2::<BigInt>(operands).map(Into::into)
+        } else {
+            part2::<isize>(operands).map(Into::into)
+        }
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![Query {
+            name: "eval",
+            args: "NAME",
+            help: "the number a monkey yells",
+            run: |operands, name| {
+                let value = if bigint::exact() {
+                    eval::<BigInt>(operands, name).map(|value| value.to_string())
+                } else {
+                    eval::<isize>(operands, name).map(|value| value.to_string())
+                };
+                value.ok_or_else(|| format!("there is no monkey called {}", name))
+            },
+        }]
+    }
+}
+
+/// Check every line is a monkey that parse_operands understands, that every monkey waited on
+/// exists, and that both root and humn are there. Then check the monkeys can be solved: none of
+/// them ends up waiting on itself, root is an operation that ends up waiting on humn, and nobody
+/// divides by a monkey that yells 0.
+fn check_monkeys(input: &str, lines: &[&str]) -> Result<()> {
+    let mut names = vec![];
+    let mut jobs = HashMap::new();
+    let mut waited_on = vec![];
+    for line in lines {
+        let (name, job) = line
+            .split_once(": ")
+            .ok_or_else(|| AocError::at(input, line, "`name: job`"))?;
+        let tokens = job.split(' ').collect::<Vec<&str>>();
+        match tokens[..] {
+            [num] => {
+                parse_at::<isize>(input, num, "a number or an operation")?;
+            }
+            [lhs, op, rhs] => {
+                if !["+", "-", "*", "/"].contains(&op) {
+                    return Err(AocError::at(input, op, "`+`, `-`, `*` or `/`"));
+                }
+                waited_on.extend([lhs, rhs]);
+            }
+            _ => return Err(AocError::at(input, job, "a number or an operation")),
+        }
+        names.push(name);
+        jobs.insert(name, (job, tokens));
+    }
+    for name in waited_on {
+        if !names.contains(&name) {
+            return Err(AocError::at(input, name, "the name of a monkey"));
+        }
+    }
+    for required in ["root", "humn"] {
+        if !names.contains(&required) {
+            return Err(AocError::at_end(
+                input,
+                format!("a monkey named {}", required),
+            ));
+        }
+    }
+
+    let (root_job, root_tokens) = &jobs["root"];
+    if root_tokens.len() != 3 {
+        return Err(AocError::at(input, root_job, "an operation for root"));
+    }
+    // yell every number exactly, each monkey after the ones it waits on, noting which of them
+    // hear from humn along the way
+    let mut values: HashMap<&str, BigInt> = HashMap::new();
+    let mut hear_humn = HashSet::from(["humn"]);
+    for name in wait_order(input, &names, &jobs)? {
+        let value = match jobs[name].1[..] {
+            [num] => BigInt::from_i64(parse_at(input, num, "a number")?),
+            [lhs, op, rhs] => {
+                if hear_humn.contains(lhs) || hear_humn.contains(rhs) {
+                    hear_humn.insert(name);
+                }
+                let (lhs_val, rhs_val) = (&values[lhs], &values[rhs]);
+                match op {
+                    "+" => lhs_val.add(rhs_val),
+                    "-" => lhs_val.sub(rhs_val),
+                    "*" => lhs_val.mul(rhs_val),
+                    _ if rhs_val.is_zero() => {
+                        return Err(AocError::at(input, rhs, "a monkey that doesn't yell 0"))
+                    }
+                    _ => lhs_val.div(rhs_val),
+                }
+            }
+            _ => unreachable!(),
+        };
+        values.insert(name, value);
+    }
+    if !hear_humn.contains("root") {
+        return Err(AocError::at(
+            input,
+            root_job,
+            "an operation that waits on humn",
+        ));
+    }
+    Ok(())
+}
+
+/// Every monkey, each after all the monkeys it waits on, or an error at the first monkey found
+/// waiting on one that is (eventually) waiting on it.
+fn wait_order<'a>(
+    input: &str,
+    names: &[&'a str],
+    jobs: &HashMap<&'a str, (&'a str, Vec<&'a str>)>,
+) -> Result<Vec<&'a str>> {
+    let mut order = vec![];
+    let mut done = HashSet::new();
+    // the monkeys on the way down to the one being looked at
+    let mut waiting = HashSet::new();
+    for &name in names {
+        // each monkey is pushed once to look at what it waits on, then again once that is done
+        let mut stack = vec![(name, false)];
+        while let Some((name, ready)) = stack.pop() {
+            if ready {
+                waiting.remove(name);
+                order.push(name);
+                done.insert(name);
+                continue;
+            }
+            if done.contains(name) {
+                continue;
+            }
+            waiting.insert(name);
+            stack.push((name, true));
+            if let [lhs, _, rhs] = jobs[name].1[..] {
+                for next in [lhs, rhs] {
+                    if waiting.contains(next) {
+                        return Err(AocError::at(
+                            input,
+                            next,
+                            "a monkey that isn't waiting on this one",
+                        ));
+                    }
+                    stack.push((next, false));
+                }
+            }
+        }
+    }
+    Ok(order)
+}
+
+/// Populate hashmap of operands
+fn parse_operands(all_lines: &[&str]) -> Operands {
+    let mut operands = Operands::new();
+    for line in all_lines.iter() {
+        // construct all the operands
+        let linesplit = line.split(' ').collect::<Vec<&str>>();
+        if linesplit.len() > 2 {
+            // Calc
+            let op = match linesplit[2] {
+                "+" => Op::Add,
+                "-" => Op::Sub,
+                "*" => Op::Mul,
+                "/" => Op::Div,
+                _ => unreachable!(),
+            };
+            let operand = Operand::Calc(op, linesplit[1].to_string(), linesplit[3].to_string());
+            operands.insert(linesplit[0][0..linesplit[0].len() - 1].to_string(), operand);
+        } else {
+            let operand = Operand::Num(linesplit[1].parse::<isize>().unwrap());
+            operands.insert(linesplit[0][0..linesplit[0].len() - 1].to_string(), operand);
+        }
+    }
+    operands
+}
+
+/// The number the monkey called `name` yells, or `None` if there is no such monkey.
+pub fn eval<N: Integer>(operands: &Operands, name: &str) -> Option<N> {
+    Some(eval_op(operands.get(name)?, operands))
+}
+
+pub fn part1<N: Integer>(operands: &Operands) -> N {
+    // evaluate root op
+    eval_op(&operands["root"], operands)
+}
+
+/// The number humn has to yell for root's two numbers to match, or `None` if there isn't one.
+pub fn part2<N: Integer>(operands: &Operands) -> Option<N> {
+    let (lhs, rhs) = match &operands["root"] {
+        Operand::Calc(_, lhs, rhs) => (lhs, rhs),
+        Operand::Num(_) => return None,
+    };
+    // print root's two values
+    debug!(
+        "root compares {} and {}",
+        eval_op::<N>(&operands[lhs], operands),
+        eval_op::<N>(&operands[rhs], operands)
+    );
+
+    let mut humn_path = node_to_humn(&operands["root"], operands, "root")?;
+    humn_path.reverse();
+    debug!("Path from humn to root is {:?}", humn_path);
+    humn_path.pop(); // pop root off the path
+    if *lhs == humn_path.pop()? {
+        let res: N = eval_op(&operands[rhs], operands);
+        eval_op_inverse(&operands[lhs], operands, &mut humn_path, res, lhs)
+    } else {
+        let res: N = eval_op(&operands[lhs], operands);
+        eval_op_inverse(&operands[rhs], operands, &mut humn_path, res, rhs)
+    }
+}
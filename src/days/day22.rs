@@ -0,0 +1,462 @@
+use crate::error::{char_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::geometry::Heading;
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::{debug, trace};
+
+/// Part 2 folds the map into a cube the way the real inputs are laid out, which only leaves the
+/// size of the faces to choose. That layout is three faces wide and four tall, so any other map,
+/// like the example's, has no part 2 answer rather than a wrong one.
+const FACE_SIZE: Param = Param {
+    day: 22,
+    name: "face_size",
+    default: 50,
+    range: 1..=i64::MAX,
+    help: "the tiles along each edge of the cube",
+};
+
+#[derive(Clone)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+#[derive(Debug)]
+enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Move(usize),
+    Turn(Turn),
+}
+
+/// (desitnation face, side)
+#[derive(Clone)]
+struct Face {
+    id: usize,
+    up: (usize, Side),
+    down: (usize, Side),
+    left: (usize, Side),
+    right: (usize, Side),
+}
+
+#[derive(Clone)]
+struct Position {
+    face: Face,
+    row: usize,
+    col: usize,
+}
+
+/// Return the next instruction in instr_str and remove it from the string
+fn next_instruction(instr_str: &mut String) -> Option<Instruction> {
+    let mut peekable_itr = instr_str.chars().peekable();
+    let mut num_digits: usize = 0;
+    let mut steps: usize = 0;
+    loop {
+        let char = peekable_itr.next();
+        let next_char = peekable_itr.peek();
+        match char {
+            Some(val) => match val {
+                'R' => {
+                    instr_str.remove(0);
+                    return Some(Instruction::Turn(Turn::Right));
+                }
+                'L' => {
+                    instr_str.remove(0);
+                    return Some(Instruction::Turn(Turn::Left));
+                }
+                _ => {
+                    num_digits += 1;
+                    steps = steps * 10 + val.to_digit(10).unwrap() as usize;
+                }
+            },
+            None => return None,
+        }
+        // keep accumulating digits until we hit L, R or line end
+        match next_char {
+            Some(val) => match val {
+                'R' => break,
+                'L' => break,
+                _ => continue,
+            },
+            None => break,
+        }
+    }
+    for _ in 0..num_digits {
+        instr_str.remove(0);
+    }
+    Some(Instruction::Move(steps))
+}
+
+/// The map, padded out to a rectangle with ` `, and the path.
+pub type Notes = (Grid<char>, String);
+
+/// Read the notes, which are a map of ` `, `.` and `#`, a blank line, then a path of step counts
+/// and L/R turns.
+fn read_notes(input: &str) -> Result<Notes> {
+    let records = input::records(input);
+    let (map, path) = match &records[..] {
+        [map, path] if path.len() == 1 && !path[0].is_empty() => (map, path[0]),
+        _ => {
+            return Err(AocError::at_end(
+                input,
+                "a map and a path separated by a blank line",
+            ))
+        }
+    };
+    let map = Grid::parse_ragged(input, map, ' ', "` `, `.` or `#`", |c| {
+        " .#".contains(c).then_some(c)
+    })?;
+    if let Some(i) = path.find(|c: char| !c.is_ascii_digit() && c != 'L' && c != 'R') {
+        let found = char_at(path, i);
+        return Err(AocError::at(input, found, "a number of steps, L or R"));
+    }
+    Ok((map, path.to_string()))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Notes;
+
+    const PARAMS: &'static [Param] = &[FACE_SIZE];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_notes(input)
+    }
+
+    fn part1(notes: &Self::Input) -> Answer {
+        part1(notes).into()
+    }
+
+    fn part2(notes: &Self::Input) -> Option<Answer> {
+        part2(notes).map(Into::into)
+    }
+}
+
+pub fn part1((map, path): &Notes) -> usize {
+    let mut instructions = path.clone();
+    // start on the leftmost open tile of the top row
+    let start_col = map.row(0).iter().position(|&tile| tile != ' ').unwrap();
+    let mut curr_pos: (usize, usize) = (0, start_col);
+    let mut curr_fac = Heading::Right;
+
+    let mut positions: Vec<(usize, usize)> = vec![];
+    let mut facings: Vec<Heading> = vec![];
+
+    while !instructions.is_empty() {
+        let instruction = next_instruction(&mut instructions).unwrap();
+        match instruction {
+            Instruction::Move(steps) => {
+                for _ in 0..steps {
+                    positions.push(curr_pos);
+                    facings.push(curr_fac);
+                    curr_pos = take_step(map, curr_pos, curr_fac);
+                }
+            }
+            Instruction::Turn(Turn::Left) => curr_fac = curr_fac.turn_left(),
+            Instruction::Turn(Turn::Right) => curr_fac = curr_fac.turn_right(),
+        }
+    }
+
+    let facing_val = facing_value(curr_fac);
+
+    trace!("{}", draw_path(map, &positions, &facings));
+    debug!(
+        "Finished at row {}, column {}, facing {:?}",
+        curr_pos.0 + 1,
+        curr_pos.1 + 1,
+        curr_fac
+    );
+    1000 * (curr_pos.0 + 1) + 4 * (curr_pos.1 + 1) + facing_val
+}
+
+/// The map with the path drawn over it, showing which way we were facing when we left each tile.
+fn draw_path(map: &Grid<char>, positions: &[(usize, usize)], facings: &[Heading]) -> Grid<char> {
+    let mut drawn = map.clone();
+    for (&pos, facing) in positions.iter().zip(facings) {
+        drawn[pos] = match facing {
+            Heading::Right => '>',
+            Heading::Left => '<',
+            Heading::Down => 'v',
+            Heading::Up => '^',
+        };
+    }
+    drawn
+}
+
+/// The password's score for each facing.
+fn facing_value(facing: Heading) -> usize {
+    match facing {
+        Heading::Right => 0,
+        Heading::Down => 1,
+        Heading::Left => 2,
+        Heading::Up => 3,
+    }
+}
+
+/// Take a step from `curr_pos` facing `curr_fac`. Stepping off the board wraps round to the
+/// other side of it, skipping over the blank padding.
+fn take_step(map: &Grid<char>, curr_pos: (usize, usize), curr_fac: Heading) -> (usize, usize) {
+    let (height, width) = (map.height() as i64, map.width() as i64);
+    let step = curr_fac.delta();
+    let mut new_pos = curr_pos;
+    loop {
+        new_pos = (
+            (new_pos.0 as i64 + step.y).rem_euclid(height) as usize,
+            (new_pos.1 as i64 + step.x).rem_euclid(width) as usize,
+        );
+        if map[new_pos] != ' ' {
+            break;
+        }
+    }
+
+    // check if new pos is blocked
+    if map[new_pos] == '#' {
+        // blocked
+        curr_pos
+    } else {
+        // move
+        new_pos
+    }
+}
+
+/// The password after walking the path round the cube, or `None` if the map isn't laid out like
+/// the real inputs' with faces of `face_size`.
+pub fn part2((map, path): &Notes) -> Option<usize> {
+    let mut instructions = path.clone();
+    let face_size = FACE_SIZE.get::<usize>();
+    if face_size.checked_mul(4) != Some(map.height()) || face_size * 3 != map.width() {
+        debug!(
+            "A {} by {} map doesn't fold into the real inputs' cube with faces of {}",
+            map.height(),
+            map.width(),
+            face_size
+        );
+        return None;
+    }
+    let faces = vec![
+        Face {
+            id: 0,
+            up: (5, Side::Bottom),
+            left: (3, Side::Left),
+            down: (2, Side::Top),
+            right: (1, Side::Left),
+        },
+        Face {
+            id: 1,
+            up: (5, Side::Bottom),
+            left: (0, Side::Right),
+            down: (2, Side::Right),
+            right: (4, Side::Right),
+        },
+        Face {
+            id: 2,
+            up: (0, Side::Bottom),
+            down: (4, Side::Top),
+            left: (3, Side::Top),
+            right: (1, Side::Bottom),
+        },
+        Face {
+            id: 3,
+            up: (2, Side::Left),
+            down: (5, Side::Top),
+            right: (4, Side::Left),
+            left: (0, Side::Left),
+        },
+        Face {
+            id: 4,
+            up: (2, Side::Bottom),
+            down: (5, Side::Right),
+            left: (3, Side::Right),
+            right: (1, Side::Right),
+        },
+        Face {
+            id: 5,
+            up: (3, Side::Bottom),
+            down: (1, Side::Top),
+            right: (4, Side::Bottom),
+            left: (0, Side::Top),
+        },
+    ];
+    let mut curr_pos: Position = Position {
+        face: faces[0].clone(),
+        row: 0,
+        col: face_size,
+    };
+    let mut curr_fac = Heading::Right;
+
+    while !instructions.is_empty() {
+        let instruction = next_instruction(&mut instructions).unwrap();
+        match instruction {
+            Instruction::Move(steps) => {
+                for _ in 0..steps {
+                    (curr_pos, curr_fac) =
+                        take_step_cube(map, &faces, face_size, curr_pos.clone(), curr_fac);
+                }
+            }
+            Instruction::Turn(Turn::Left) => curr_fac = curr_fac.turn_left(),
+            Instruction::Turn(Turn::Right) => curr_fac = curr_fac.turn_right(),
+        }
+    }
+
+    let facing_val = facing_value(curr_fac);
+
+    let pos = face_pos_to_map_pos(&curr_pos, face_size);
+    debug!(
+        "Finished at row {}, column {}, facing {:?}",
+        pos.0 + 1,
+        pos.1 + 1,
+        curr_fac
+    );
+    Some(1000 * (pos.0 + 1) + 4 * (pos.1 + 1) + facing_val)
+}
+
+fn face_pos_to_map_pos(curr_pos: &Position, face_size: usize) -> (usize, usize) {
+    let origins = [
+        (face_size, 0),
+        (2 * face_size, 0),
+        (face_size, face_size),
+        (0, 2 * face_size),
+        (face_size, 2 * face_size),
+        (0, 3 * face_size),
+    ];
+    let origin = origins[curr_pos.face.id];
+    (origin.0 + curr_pos.row, origin.1 + curr_pos.col)
+}
+
+fn take_step_cube(
+    map: &Grid<char>,
+    faces: &[Face],
+    face_size: usize,
+    curr_pos: Position,
+    curr_fac: Heading,
+) -> (Position, Heading) {
+    let step = curr_fac.delta();
+    let new_coord: (i64, i64) = (curr_pos.row as i64 + step.y, curr_pos.col as i64 + step.x);
+    let mut new_pos: Position = curr_pos.clone();
+    let new_fac;
+    if new_coord.0 < 0 {
+        // move off left of face
+        let (new_face_id, new_face_side) = &curr_pos.face.left;
+        match new_face_side {
+            Side::Left => {
+                new_pos.col = 0;
+                new_pos.row = face_size - 1 - new_pos.row;
+                new_fac = Heading::Right;
+            }
+            Side::Right => {
+                new_pos.col = face_size - 1;
+                new_fac = Heading::Left;
+            }
+            Side::Top => {
+                new_pos.col = new_pos.row;
+                new_pos.row = 0;
+                new_fac = Heading::Down;
+            }
+            Side::Bottom => {
+                new_pos.col = new_pos.row;
+                new_pos.row = face_size - 1;
+                new_fac = Heading::Left;
+            }
+        }
+        new_pos.face = faces[*new_face_id].clone();
+    } else if new_coord.0 >= face_size as i64 {
+        // move off right of face
+        let (new_face_id, new_face_side) = &curr_pos.face.right;
+        match new_face_side {
+            Side::Left => {
+                new_pos.col = 0;
+                new_fac = Heading::Right;
+            }
+            Side::Right => {
+                new_pos.col = face_size - 1;
+                new_pos.row = face_size - 1 - new_pos.row;
+                new_fac = Heading::Left;
+            }
+            Side::Top => {
+                new_pos.col = new_pos.row;
+                new_pos.row = 0;
+                new_fac = Heading::Down;
+            }
+            Side::Bottom => {
+                new_pos.col = new_pos.row;
+                new_pos.row = face_size - 1;
+                new_fac = Heading::Left;
+            }
+        }
+        new_pos.face = faces[*new_face_id].clone();
+    } else if new_coord.1 < 0 {
+        // move off top of face
+        let (new_face_id, new_face_side) = &curr_pos.face.up;
+        match new_face_side {
+            Side::Left => {
+                new_pos.row = new_pos.col;
+                new_pos.col = 0;
+                new_fac = Heading::Right;
+            }
+            Side::Right => {
+                new_pos.row = new_pos.col;
+                new_pos.col = face_size - 1;
+                new_fac = Heading::Left;
+            }
+            Side::Top => {
+                new_pos.row = 0;
+                new_pos.col = face_size - 1 - new_pos.col;
+                new_fac = Heading::Down;
+            }
+            Side::Bottom => {
+                new_pos.row = face_size - 1;
+                new_fac = Heading::Left;
+            }
+        }
+        new_pos.face = faces[*new_face_id].clone();
+    } else if new_coord.1 >= face_size as i64 {
+        // move off bottom of face
+        let (new_face_id, new_face_side) = &curr_pos.face.down;
+        match new_face_side {
+            Side::Left => {
+                new_pos.row = new_pos.col;
+                new_pos.col = 0;
+                new_fac = Heading::Right;
+            }
+            Side::Right => {
+                new_pos.row = new_pos.col;
+                new_pos.col = face_size - 1;
+                new_fac = Heading::Left;
+            }
+            Side::Top => {
+                new_pos.row = 0;
+                new_fac = Heading::Down;
+            }
+            Side::Bottom => {
+                new_pos.row = face_size - 1;
+                new_pos.col = face_size - 1 - new_pos.col;
+                new_fac = Heading::Left;
+            }
+        }
+        new_pos.face = faces[*new_face_id].clone();
+    } else {
+        new_pos.row = new_coord.0 as usize;
+        new_pos.col = new_coord.1 as usize;
+        new_fac = curr_fac;
+    }
+
+    let map_pos = face_pos_to_map_pos(&new_pos, face_size);
+
+    // check if new pos is blocked
+    if map.get(map_pos) == Some(&'#') {
+        // blocked
+        (curr_pos, curr_fac)
+    } else {
+        // move
+        (new_pos, new_fac)
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::error::AocError;
+use crate::solution::{Answer, Result, Solution};
+use crate::stepper::{Simulation, Steppable, Stepper};
+use crate::util::geometry::{Heading, Point2};
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::util::render::{Picture, Rgb, Style};
+use crate::{debug, trace};
+
+pub type Elves = HashSet<Point2>;
+
+/// Check the three squares ahead of `elf` along `heading`, diagonals included, are free
+fn check(elf: Point2, heading: Heading, elves: &Elves) -> bool {
+    let ahead = elf + heading.delta();
+    !(elves.contains(&ahead)
+        || elves.contains(&(ahead + heading.turn_left().delta()))
+        || elves.contains(&(ahead + heading.turn_right().delta())))
+}
+
+fn check_all(elf: Point2, elves: &Elves) -> bool {
+    Heading::ALL
+        .iter()
+        .all(|&heading| check(elf, heading, elves))
+}
+
+/// The smallest rectangle holding every elf, as the puzzle draws it.
+fn draw_elves(elves: &Elves) -> Picture {
+    Picture::from_points(elves.iter().copied(), Style::new('.', Rgb::DARK), |_| {
+        Style::new('#', Rgb::GREEN)
+    })
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    /// Starting position of every elf.
+    type Input = Elves;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_elves(input)
+    }
+
+    fn part1(elves: &Self::Input) -> Answer {
+        part1(elves).into()
+    }
+
+    fn part2(elves: &Self::Input) -> Option<Answer> {
+        Some(part2(elves).into())
+    }
+
+    /// The elves spreading out, a round at a time. `empty` counts the empty ground in the
+    /// smallest rectangle holding every elf, which part 1 asks for after ten rounds.
+    fn simulate(elves: &Self::Input) -> Option<Box<dyn Steppable>> {
+        Some(Box::new(Stepper::new(Spreading {
+            elves: elves.clone(),
+        })))
+    }
+
+    /// Where the elves are after the ten rounds of part 1.
+    fn render(elves: &Self::Input) -> Option<Picture> {
+        let (elves, _) = spread_out(elves, 10);
+        Some(draw_elves(&elves))
+    }
+}
+
+fn read_elves(input: &str) -> Result<Elves> {
+    let map = Grid::parse(input, &input::lines(input), "`#` or `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    let elves: Elves = map
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|((i, j), _)| Point2::new(j as i64, i as i64))
+        .collect();
+    if elves.is_empty() {
+        return Err(AocError::at_end(input, "at least one elf `#`"));
+    }
+    Ok(elves)
+}
+
+/// Where the elves are after round `i` (counting from zero), which starts by considering the
+/// `i`th heading of north, south, west and east, wrapping round.
+fn play_round(elves: &Elves, i: usize) -> Elves {
+    let headings = [Heading::Up, Heading::Down, Heading::Left, Heading::Right];
+    let mut new_elves: Elves = HashSet::new();
+    'outer: for &elf in elves.iter() {
+        if check_all(elf, elves) {
+            new_elves.insert(elf);
+            continue;
+        } else {
+            for j in 0..4 {
+                let heading = headings[(i + j) % 4];
+                if check(elf, heading, elves) {
+                    let new_elf = elf + heading.delta();
+                    if !new_elves.insert(new_elf) {
+                        // insert was rejected, reset other elf and this elf
+                        new_elves.insert(elf);
+                        new_elves.remove(&new_elf);
+                        let old_elf = new_elf + heading.delta();
+                        new_elves.insert(old_elf);
+                    };
+                    continue 'outer;
+                }
+            }
+            new_elves.insert(elf);
+        }
+    }
+    new_elves
+}
+
+/// The elves spreading out, a round at a time, until none of them move.
+struct Spreading {
+    elves: Elves,
+}
+
+impl Simulation for Spreading {
+    /// The elves, and how many rounds they have played.
+    type State = (Elves, usize);
+
+    fn start(&self) -> Self::State {
+        (self.elves.clone(), 0)
+    }
+
+    fn step(&self, (elves, round): &Self::State) -> Option<Self::State> {
+        let new_elves = play_round(elves, *round);
+        (new_elves != *elves).then_some((new_elves, round + 1))
+    }
+
+    fn draw(&self, (elves, _): &Self::State) -> Picture {
+        draw_elves(elves)
+    }
+
+    fn values(&self, (elves, round): &Self::State) -> Vec<(&'static str, i64)> {
+        vec![("round", *round as i64), ("empty", empty_ground(elves))]
+    }
+}
+
+/// Let the elves spread out for at most `max_rounds` rounds, or until no elf moves. Returns the
+/// final positions of the elves and the number of rounds that were played.
+fn spread_out(elves: &Elves, max_rounds: usize) -> (Elves, usize) {
+    let mut elves = elves.clone();
+
+    // iterate
+    let mut i = 0;
+    while i < max_rounds {
+        let new_elves = play_round(&elves, i);
+        if new_elves == elves {
+            debug!("No elves moved on round {}", i + 1);
+            break;
+        }
+        elves = new_elves;
+        i += 1;
+    }
+    (elves, i)
+}
+
+pub fn part1(elves: &Elves) -> i64 {
+    let (elves, _) = spread_out(elves, 10);
+    trace!("{}", draw_elves(&elves));
+    empty_ground(&elves)
+}
+
+/// Empty squares in the smallest rectangle holding every elf.
+fn empty_ground(elves: &Elves) -> i64 {
+    let min_row = elves.iter().map(|elf| elf.y).min().unwrap();
+    let max_row = elves.iter().map(|elf| elf.y).max().unwrap();
+    let min_col = elves.iter().map(|elf| elf.x).min().unwrap();
+    let max_col = elves.iter().map(|elf| elf.x).max().unwrap();
+    (max_row - min_row + 1) * (max_col - min_col + 1) - elves.len() as i64
+}
+
+pub fn part2(elves: &Elves) -> usize {
+    let (_, rounds) = spread_out(elves, usize::MAX);
+    rounds + 1
+}
@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+use ndarray::Array3;
+
+use crate::error::AocError;
+use crate::solution::{Answer, Result, Solution};
+use crate::stepper::{Simulation, Steppable, Stepper};
+use crate::util::geometry::{Heading, Point2};
+use crate::util::grid::Grid;
+use crate::util::input;
+use crate::util::math;
+use crate::util::render::{Picture, Rgb, Style};
+use crate::util::search;
+use crate::{debug, trace};
+
+pub type Blizz = (Point2, Heading);
+pub type Bounds = (usize, usize, usize, usize);
+/// The bounds of the valley, the blizzard map described in [`storm_map`], and the blizzards as
+/// they start out.
+pub type StormMap = (Bounds, Array3<usize>, Vec<Blizz>);
+
+/// The valley as the puzzle draws it, with a count wherever blizzards overlap.
+fn draw_storm(blizzards: &[Blizz], bounds: Bounds) -> Picture {
+    let mut overlaps: Grid<Vec<Heading>> = Grid::new(bounds.2 + 1, bounds.1 + 1, vec![]);
+    for &(pos, heading) in blizzards {
+        overlaps[pos.cast::<usize>().unwrap().pos()].push(heading);
+    }
+    let openings = [(0, 1), (bounds.2, bounds.1 - 1)];
+    Picture::from_fn(overlaps.height(), overlaps.width(), |pos| {
+        let (row, col) = pos;
+        let wall = row == 0 || col == 0 || row == bounds.2 || col == bounds.1;
+        match overlaps[pos][..] {
+            _ if wall && !openings.contains(&pos) => Style::new('#', Rgb::GREY),
+            [] => Style::new('.', Rgb::DARK),
+            [heading] => {
+                let arrow = match heading {
+                    Heading::Up => '^',
+                    Heading::Right => '>',
+                    Heading::Down => 'v',
+                    Heading::Left => '<',
+                };
+                Style::new(arrow, Rgb::ICE)
+            }
+            ref many => {
+                let count = char::from_digit(many.len().min(9) as u32, 10).unwrap();
+                Style::new(count, Rgb::WHITE)
+            }
+        }
+    })
+}
+
+/// Where `blizzards` are `t` minutes later.
+fn blow(blizzards: &[Blizz], bounds: Bounds, t: usize) -> Vec<Blizz> {
+    // blizzards wrap round within the walls
+    let (inner_width, inner_height) = ((bounds.1 - 1) as i64, (bounds.2 - 1) as i64);
+    blizzards
+        .iter()
+        .map(|&(pos, heading)| {
+            let next = pos + heading.delta() * t as i64;
+            let next = Point2::new(
+                (next.x - 1).rem_euclid(inner_width) + 1,
+                (next.y - 1).rem_euclid(inner_height) + 1,
+            );
+            (next, heading)
+        })
+        .collect()
+}
+
+/// Read the valley and precalculate which squares are covered by a blizzard at every time step
+/// until the blizzards repeat, `map[(t, row, col)] == 1` if there is a blizzard at (row, col) at
+/// time t. Use [`clear`] to look up later times.
+fn storm_map(input: &str) -> Result<StormMap> {
+    let all_lines = input::lines(input);
+    if all_lines.len() < 3 {
+        return Err(AocError::at_end(input, "a walled valley"));
+    }
+
+    let valley = Grid::parse(input, &all_lines, "`#`, `.`, `^`, `>`, `v` or `<`", |c| {
+        "#.^>v<".contains(c).then_some(c)
+    })?;
+    if valley.width() < 3 {
+        return Err(AocError::at(
+            input,
+            all_lines[0],
+            "a walled valley at least 3 wide",
+        ));
+    }
+    let bounds = (0, valley.width() - 1, valley.height() - 1, 0);
+    let blizzards: Vec<Blizz> = valley
+        .iter()
+        .filter_map(|((i, j), c)| {
+            let heading = match c {
+                '^' => Heading::Up,
+                '>' => Heading::Right,
+                'v' => Heading::Down,
+                '<' => Heading::Left,
+                _ => return None,
+            };
+            Some((Point2::new(j as i64, i as i64), heading))
+        })
+        .collect();
+    let (inner_width, inner_height) = (bounds.1 - 1, bounds.2 - 1);
+
+    // blizzards going across are back where they started every inner_width steps and those
+    // going up and down every inner_height, so the whole valley repeats every lcm of the two
+    let period = math::lcm(inner_width, inner_height);
+
+    let mut map = Array3::<usize>::zeros([period, bounds.2 + 1, bounds.1 + 1]);
+    for t in 0..period {
+        let blown = blow(&blizzards, bounds, t);
+        trace!("Step {}:\n{}", t, draw_storm(&blown, bounds));
+        for (pos, _) in blown {
+            map[(t, pos.y as usize, pos.x as usize)] = 1;
+        }
+    }
+
+    Ok((bounds, map, blizzards))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = StormMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        storm_map(input)
+    }
+
+    fn part1(storm: &Self::Input) -> Answer {
+        part1(storm).into()
+    }
+
+    fn part2(storm: &Self::Input) -> Option<Answer> {
+        Some(part2(storm).into())
+    }
+
+    /// Everywhere the expedition could be on each minute of part 1, until one of them is the
+    /// exit.
+    fn simulate(storm: &Self::Input) -> Option<Box<dyn Steppable>> {
+        Some(Box::new(Stepper::new(Crossing {
+            storm: storm.clone(),
+        })))
+    }
+
+    /// The valley before the blizzards start to move.
+    fn render((bounds, _, blizzards): &Self::Input) -> Option<Picture> {
+        Some(draw_storm(blizzards, *bounds))
+    }
+}
+
+/// The crossing of part 1, a minute at a time, following every position the expedition could be
+/// in at once.
+struct Crossing {
+    storm: StormMap,
+}
+
+impl Crossing {
+    fn exit(&self) -> (usize, usize) {
+        let (bounds, _, _) = self.storm;
+        (bounds.2, bounds.1 - 1)
+    }
+}
+
+impl Simulation for Crossing {
+    /// The minute, and the positions that can be reached by then.
+    type State = (usize, HashSet<(usize, usize)>);
+
+    fn start(&self) -> Self::State {
+        (0, HashSet::from([(0, 1)]))
+    }
+
+    fn step(&self, (t, reachable): &Self::State) -> Option<Self::State> {
+        if reachable.contains(&self.exit()) {
+            return None;
+        }
+        let (bounds, map, _) = &self.storm;
+        let next = reachable
+            .iter()
+            .flat_map(|&(row, col)| moves(*bounds, map, (*t, row, col), self.exit()))
+            .map(|(_, row, col)| (row, col))
+            .collect();
+        Some((t + 1, next))
+    }
+
+    fn draw(&self, (t, reachable): &Self::State) -> Picture {
+        let (bounds, _, blizzards) = &self.storm;
+        let mut picture = draw_storm(&blow(blizzards, *bounds, *t), *bounds);
+        for &pos in reachable {
+            picture.set(pos, Style::new('E', Rgb::GREEN));
+        }
+        picture
+    }
+
+    fn values(&self, (t, reachable): &Self::State) -> Vec<(&'static str, i64)> {
+        vec![("minute", *t as i64), ("reachable", reachable.len() as i64)]
+    }
+}
+
+pub fn part1((bounds, map, _): &StormMap) -> usize {
+    let bounds = *bounds;
+    // pathfind through map with bfs
+    let init_pos = (0, 0, 1);
+    let exit = (bounds.2, bounds.1 - 1);
+
+    let exit_t = set_bfs(bounds, map, init_pos, exit);
+    debug!("Reached exit in {} steps", exit_t);
+    exit_t
+}
+
+pub fn part2((bounds, map, _): &StormMap) -> usize {
+    let bounds = *bounds;
+    // pathfind through map with bfs
+    let init_pos = (0, 0, 1);
+    let exit = (bounds.2, bounds.1 - 1);
+
+    let exit_t = set_bfs(bounds, map, init_pos, exit);
+
+    // pathfind back through map with bfs
+    let init_pos = (exit_t, exit.0, exit.1);
+    let exit = (0, 1);
+
+    let entrance_t = set_bfs(bounds, map, init_pos, exit);
+
+    // pathfind AGAIN back through map with bfs
+    let init_pos = (entrance_t, 0, 1);
+    let exit = (bounds.2, bounds.1 - 1);
+
+    let final_t = set_bfs(bounds, map, init_pos, exit);
+
+    debug!(
+        "Reached exit in {} steps, entrance in {} steps, and exit again in {} steps",
+        exit_t,
+        entrance_t - exit_t,
+        final_t - entrance_t
+    );
+    final_t
+}
+
+/// Fewest steps to get from `init_pos`, as `(t, row, col)`, to `exit`, returned as the time
+/// the exit is reached.
+fn set_bfs(
+    bounds: Bounds,
+    map: &Array3<usize>,
+    init_pos: (usize, usize, usize),
+    exit: (usize, usize),
+) -> usize {
+    // every move takes a step, and the blizzards can only hold us up, so the distance to the exit
+    // never overestimates the steps left
+    let search = search::astar(
+        init_pos,
+        |&curr_pos| {
+            moves(bounds, map, curr_pos, exit)
+                .into_iter()
+                .map(|pos| (pos, 1))
+        },
+        |&(_, row, col)| row.abs_diff(exit.0) + col.abs_diff(exit.1),
+        |&(_, row, col)| (row, col) == exit,
+    );
+    let exit_t = search.goal().unwrap().0;
+    trace!("Exit found after {} steps", exit_t);
+    exit_t
+}
+
+/// Whether `(t, row, col)` is free of blizzards. The blizzards start again from the beginning
+/// once `map` runs out.
+fn clear(map: &Array3<usize>, (t, row, col): (usize, usize, usize)) -> bool {
+    map[(t % map.dim().0, row, col)] == 0
+}
+
+/// The positions that can be reached one step after `curr_pos` without being hit by a blizzard.
+fn moves(
+    bounds: Bounds,
+    map: &Array3<usize>,
+    curr_pos: (usize, usize, usize),
+    exit: (usize, usize),
+) -> Vec<(usize, usize, usize)> {
+    let mut next = vec![];
+    // wait where we are
+    let next_pos = (curr_pos.0 + 1, curr_pos.1, curr_pos.2);
+    if clear(map, next_pos) {
+        next.push(next_pos);
+    }
+    // move down
+    let next_pos = (curr_pos.0 + 1, curr_pos.1 + 1, curr_pos.2);
+    if next_pos.1 < bounds.2 && clear(map, next_pos) {
+        next.push(next_pos);
+    }
+    // special case for entering exit
+    if (next_pos.1, next_pos.2) == exit && clear(map, next_pos) {
+        next.push(next_pos);
+    }
+    // move right
+    let next_pos = (curr_pos.0 + 1, curr_pos.1, curr_pos.2 + 1);
+    // move is valid
+    // if we're not at the entrance, hitting the bound, or hitting a blizzard
+    if next_pos.1 > 0 && next_pos.1 < bounds.2 && next_pos.2 < bounds.1 && clear(map, next_pos) {
+        next.push(next_pos);
+    }
+    // move left
+    let next_pos = (curr_pos.0 + 1, curr_pos.1, curr_pos.2 - 1);
+    // move is valid
+    // if we're not at the entrance or exit row, hitting the bound, or hitting a blizzard
+    if next_pos.1 > 0 && next_pos.1 < bounds.2 && next_pos.2 > bounds.3 && clear(map, next_pos) {
+        next.push(next_pos);
+    }
+    // move up
+    if curr_pos.1 > 0 {
+        // account for being stood at entrance
+        let next_pos = (curr_pos.0 + 1, curr_pos.1 - 1, curr_pos.2);
+        if next_pos.1 > bounds.0 && clear(map, next_pos) {
+            next.push(next_pos);
+        }
+        // special case for entering entrance
+        if (next_pos.1, next_pos.2) == (0, 1) && clear(map, next_pos) {
+            next.push(next_pos);
+        }
+    }
+    next
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::error::{char_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::bigint::{self, BigInt, Integer};
+use crate::util::input;
+
+//  = -> -2
+//  - -> -1
+//  0 -> 0
+//  1 -> 1
+//  2 -> 2
+//
+// can just construct new digits in base 5
+// 0 -> 0
+// 1 -> 1
+// 2 -> 2
+// 3 -> 1=
+// 4 -> 1-
+// 5 -> 10
+// 6 -> 11
+// 7 -> 12
+// 8 -> 2=
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SNAFU {
+    digits: Vec<char>,
+}
+
+/// Each SNAFU digit and its value.
+const DIGITS: [(char, i64); 5] = [('=', -2), ('-', -1), ('0', 0), ('1', 1), ('2', 2)];
+
+impl SNAFU {
+    /// `num` in SNAFU: the usual base 5 digits, except that a digit of 3 or 4 becomes -2 or -1
+    /// with one carried into the next digit up.
+    pub fn from_decimal<N: Integer>(num: &N) -> SNAFU {
+        let (zero, five) = (N::from_i64(0), N::from_i64(5));
+        let mut digits = vec![];
+        let mut rest = num.clone();
+        while rest != zero {
+            let digit = rest.rem(&five);
+            let digit = if digit > N::from_i64(2) {
+                digit.sub(&five)
+            } else if digit < N::from_i64(-2) {
+                digit.add(&five)
+            } else {
+                digit
+            };
+            let (c, _) = DIGITS
+                .iter()
+                .find(|&&(_, value)| N::from_i64(value) == digit)
+                .unwrap();
+            digits.push(*c);
+            rest = rest.sub(&digit).div(&five);
+        }
+        if digits.is_empty() {
+            digits.push('0');
+        }
+        digits.reverse();
+        SNAFU { digits }
+    }
+
+    /// The value of the number, worked out in `N`.
+    pub fn to_decimal<N: Integer>(&self) -> N {
+        let five = N::from_i64(5);
+        self.digits.iter().fold(N::from_i64(0), |result, digit| {
+            let (_, value) = DIGITS.iter().find(|&&(c, _)| c == *digit).unwrap();
+            result.mul(&five).add(&N::from_i64(*value))
+        })
+    }
+
+    /// The sum of `numbers`, in SNAFU, doing the sums in `N`.
+    pub fn sum<N: Integer>(numbers: &[SNAFU]) -> SNAFU {
+        let total = numbers
+            .iter()
+            .fold(N::from_i64(0), |total, x| total.add(&x.to_decimal()));
+        SNAFU::from_decimal(&total)
+    }
+}
+
+impl fmt::Display for SNAFU {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.digits.iter().collect::<String>())
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    /// Fuel requirement of every hot air balloon.
+    type Input = Vec<SNAFU>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input::lines(input)
+            .iter()
+            .map(|line| {
+                if let Some(i) = line.find(|c| !"=-012".contains(c)) {
+                    let found = char_at(line, i);
+                    return Err(AocError::at(
+                        input,
+                        found,
+                        "a SNAFU digit (=, -, 0, 1 or 2)",
+                    ));
+                }
+                Ok(SNAFU {
+                    digits: line.chars().collect::<Vec<char>>(),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        part1(numbers).to_string().into()
+    }
+}
+
+/// The total fuel requirement, with the sum done exactly when exact arithmetic is turned on.
+pub fn part1(numbers: &[SNAFU]) -> SNAFU {
+    if bigint::exact() {
+        SNAFU::sum::<BigInt>(numbers)
+    } else {
+        SNAFU::sum::<i64>(numbers)
+    }
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
+
+use crate::error::{char_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
 
 #[derive(Debug)]
 struct Backpack {
@@ -42,11 +46,9 @@ fn bisect<T: std::cmp::PartialEq + std::cmp::PartialOrd>(v: Vec<T>, val: T) -> O
     None
 }
 
-/// We can devise a O(n log(n)) solution to this problem using sorting and
-/// bisection, rather than the O(n^2) solution of comparing every item
-/// in the two compartments
-fn main() {
-    let priorities: HashMap<char, usize> = HashMap::from([
+/// Map each item type to its priority, a-z => 1-26 and A-Z => 27-52
+fn priorities() -> HashMap<char, usize> {
+    HashMap::from([
         ('a', 1),
         ('b', 2),
         ('c', 3),
@@ -99,12 +101,54 @@ fn main() {
         ('X', 50),
         ('Y', 51),
         ('Z', 52),
-    ]);
+    ])
+}
+
+pub struct Day3;
 
-    let input = fs::read_to_string("day3_1.txt").expect("Error opening file");
+impl Solution for Day3 {
+    /// The contents of each rucksack, one per line.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        let lines = input::lines(input);
+        for line in lines.iter() {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                let found = char_at(line, i);
+                return Err(AocError::at(
+                    input,
+                    found,
+                    "an item type from a to z or A to Z",
+                ));
+            }
+            if line.len() % 2 != 0 {
+                return Err(AocError::at(
+                    input,
+                    line,
+                    "two compartments of the same size",
+                ));
+            }
+        }
+        Ok(lines.iter().map(|x| x.to_string()).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Vec<String>) -> Option<Answer> {
+        Some(part2(lines).into())
+    }
+}
+
+/// We can devise a O(n log(n)) solution to this problem using sorting and
+/// bisection, rather than the O(n^2) solution of comparing every item
+/// in the two compartments
+pub fn part1(lines: &[String]) -> usize {
+    let priorities = priorities();
     let mut accumulator = 0;
 
-    for line in input.split("\r\n") {
+    for line in lines {
         let mut bp = Backpack::from_str(line);
         // Sorting each compartment is O(N)
         bp.compartment1
@@ -126,10 +170,12 @@ fn main() {
             }
         }
     }
-    println!("{}", accumulator);
+    accumulator
+}
 
-    let lines = input.split("\r\n").collect::<Vec<&str>>();
-    assert!(lines.len() % 3 == 0);
+pub fn part2(lines: &[String]) -> usize {
+    let priorities = priorities();
+    assert!(lines.len().is_multiple_of(3));
     let mut accumulator = 0;
     for i in 0..lines.len() / 3 {
         // Init and sort backpacks. No need for compartments here.
@@ -144,7 +190,7 @@ fn main() {
         let mut matching_items_bp2: Vec<char> = vec![];
         for item in first_bp {
             let val = priorities[&item];
-            let prios = second_bp.iter().map(|x| priorities[&x]).collect();
+            let prios = second_bp.iter().map(|x| priorities[x]).collect();
             let second_bp_pos = bisect(prios, val);
             match second_bp_pos {
                 Some(_) => {
@@ -156,7 +202,7 @@ fn main() {
         // Find matching items between backpacks of second and third elf
         for item in matching_items_bp2 {
             let val = priorities[&item];
-            let prios = third_bp.iter().map(|x| priorities[&x]).collect();
+            let prios = third_bp.iter().map(|x| priorities[x]).collect();
             let third_bp_pos = bisect(prios, val);
             match third_bp_pos {
                 Some(_) => {
@@ -167,5 +213,5 @@ fn main() {
             }
         }
     }
-    println!("{}", accumulator);
+    accumulator
 }
@@ -0,0 +1,67 @@
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::util::interval::IntervalSet;
+
+pub type Ranges = Vec<(IntervalSet<usize>, IntervalSet<usize>)>;
+
+/// Split lines in the form "a-b,c-d" into the sections a to b and c to d
+fn parse_ranges(input: &str, line: &str) -> Result<(IntervalSet<usize>, IntervalSet<usize>)> {
+    let (first_range, second_range) = line
+        .split_once(',')
+        .ok_or_else(|| AocError::at(input, line, "two ranges separated by a comma"))?;
+    Ok((
+        parse_range(input, first_range)?,
+        parse_range(input, second_range)?,
+    ))
+}
+
+/// Read a range in the form "a-b", which includes both a and b
+fn parse_range(input: &str, range: &str) -> Result<IntervalSet<usize>> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| AocError::at(input, range, "a range like 2-4"))?;
+    let start: usize = parse_at(input, start, "a section number")?;
+    let end: usize = parse_at(input, end, "a section number")?;
+    Ok(IntervalSet::from(start..end + 1))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    /// The pair of section ranges assigned to each pair of elves.
+    type Input = Ranges;
+
+    fn parse(input: &str) -> Result<Ranges> {
+        input::lines(input)
+            .iter()
+            .map(|x| parse_ranges(input, x))
+            .collect()
+    }
+
+    fn part1(pairs: &Ranges) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(pairs: &Ranges) -> Option<Answer> {
+        Some(part2(pairs).into())
+    }
+}
+
+pub fn part1(pairs: &Ranges) -> usize {
+    // one range contains the other if adding it changes nothing
+    pairs
+        .iter()
+        .filter(|(first, second)| {
+            let union = first.union(second);
+            union == *first || union == *second
+        })
+        .count()
+}
+
+pub fn part2(pairs: &Ranges) -> usize {
+    pairs
+        .iter()
+        .filter(|(first, second)| !first.intersection(second).is_empty())
+        .count()
+}
@@ -0,0 +1,132 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::error::{parse_at, AocError};
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+
+pub type Stacks = Vec<Vec<char>>;
+
+/// Number of crates to move, and the 1-based stacks to move them from and to.
+pub type Instruction = (usize, usize, usize);
+
+/// Returns `stacks`, a num_stacks long vector of variable length vectors
+/// holding the chars corresponding to crates on each stack, and the
+/// 'instructions' for rearranging them
+fn parse_input(input: &str) -> Result<(Stacks, Vec<Instruction>)> {
+    // Parsing the input for this one seems tricky.
+    // Luckily the input -does- have some regularity. All the entries in each
+    // column are three characters followed by a space. We can use this to parse
+    let records = input::records(input);
+    let (linebuf, instructions) = match &records[..] {
+        [linebuf, instructions] => (linebuf, instructions),
+        _ => {
+            return Err(AocError::at_end(
+                input,
+                "a crate drawing and a rearrangement procedure separated by a blank line",
+            ))
+        }
+    };
+    // Now we have all the crate lines in a buffer, we iterate in reverse order
+    // and populate stacks with them
+    let mut cratelines = linebuf.iter().rev();
+    let numstack = match cratelines.next() {
+        Some(numbers) => numbers.split_whitespace().count(),
+        None => return Err(AocError::at(input, input, "a drawing of the crates")),
+    };
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; numstack];
+    for line in cratelines {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                if i >= numstack {
+                    return Err(AocError::at(input, line, "a crate on a numbered stack"));
+                }
+                stacks[i].push(c);
+            }
+        }
+    }
+    let instructions = instructions
+        .iter()
+        .map(|x| parse_instruction(input, x, numstack))
+        .collect::<Result<_>>()?;
+    Ok((stacks, instructions))
+}
+
+fn parse_instruction(input: &str, line: &str, numstack: usize) -> Result<Instruction> {
+    lazy_static! {
+        static ref MOVE_RE: Regex =
+            Regex::new(r"^move ([0-9]+) from ([0-9]+) to ([0-9]+)$").unwrap();
+    }
+    let c = MOVE_RE
+        .captures(line)
+        .ok_or_else(|| AocError::at(input, line, "`move N from A to B`"))?;
+    let stack = |i: usize| {
+        let token = c.get(i).unwrap().as_str();
+        match parse_at(input, token, "a stack number")? {
+            n if (1..=numstack).contains(&n) => Ok(n),
+            _ => Err(AocError::at(
+                input,
+                token,
+                format!("a stack between 1 and {}", numstack),
+            )),
+        }
+    };
+    let (stack_from, stack_to) = (stack(2)?, stack(3)?);
+    let num_to_move = parse_at(input, c.get(1).unwrap().as_str(), "a number of crates")?;
+    Ok((num_to_move, stack_from, stack_to))
+}
+
+fn tops(stacks: &[Vec<char>]) -> String {
+    stacks.iter().map(|x| x.last().unwrap()).collect::<String>()
+}
+
+fn cratemover_9000(stacks: &mut [Vec<char>], instruction: &Instruction) {
+    let (num_to_move, stack_from, stack_to) = *instruction;
+    for _ in 0..num_to_move {
+        let val = stacks[stack_from - 1].pop().unwrap();
+        stacks[stack_to - 1].push(val);
+    }
+}
+
+fn cratemover_9001(stacks: &mut [Vec<char>], instruction: &Instruction) {
+    let (num_to_move, stack_from, stack_to) = *instruction;
+    let split_idx = stacks[stack_from - 1].len() - num_to_move;
+    let move_crates = stacks[stack_from - 1].split_off(split_idx);
+    stacks[stack_to - 1].extend(move_crates);
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Stacks, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(procedure: &Self::Input) -> Answer {
+        part1(procedure).into()
+    }
+
+    fn part2(procedure: &Self::Input) -> Option<Answer> {
+        Some(part2(procedure).into())
+    }
+}
+
+/// The crates on top of each stack after moving them one at a time.
+pub fn part1((stacks, instructions): &(Stacks, Vec<Instruction>)) -> String {
+    let mut stacks = stacks.clone();
+    for instruction in instructions {
+        cratemover_9000(&mut stacks, instruction);
+    }
+    tops(&stacks)
+}
+
+/// The crates on top of each stack after moving them several at a time.
+pub fn part2((stacks, instructions): &(Stacks, Vec<Instruction>)) -> String {
+    let mut stacks = stacks.clone();
+    for instruction in instructions {
+        cratemover_9001(&mut stacks, instruction);
+    }
+    tops(&stacks)
+}
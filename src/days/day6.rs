@@ -0,0 +1,68 @@
+use std::collections::VecDeque;
+
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+use crate::{debug, trace};
+
+/// This week was a fun one. The code I wrote for part 1 was trivially
+/// capable of solving part 2 as well, the only difference is the length of
+/// the marker we are looking for.
+pub struct Day6;
+
+impl Solution for Day6 {
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input::trim(input).to_string())
+    }
+
+    fn part1(input: &String) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &String) -> Option<Answer> {
+        Some(part2(input).into())
+    }
+}
+
+/// Characters read by the end of the first start-of-packet marker.
+pub fn part1(input: &str) -> usize {
+    find_marker(input, 4)
+}
+
+/// Characters read by the end of the first start-of-message marker.
+pub fn part2(input: &str) -> usize {
+    find_marker(input, 14)
+}
+
+/// Number of characters read by the end of the first run of `message_len`
+/// distinct characters.
+pub fn find_marker(input: &str, message_len: usize) -> usize {
+    let mut buf: VecDeque<char> = VecDeque::from(vec![]);
+    let mut total = 0;
+    for c in input.chars() {
+        trace!("{}", buf.iter().collect::<String>());
+        total += 1;
+        if buf.contains(&c) {
+            // Break off buffer at first occurence of c
+            trace!("{} repeats", c);
+            while buf[0] != c {
+                buf.pop_front();
+            }
+            buf.pop_front();
+        };
+        // Push c into the end of the buffer
+        buf.push_back(c);
+        if buf.len() >= message_len {
+            // Start-of-packet marker detected
+            debug!(
+                "Marker {} ends at character {}",
+                buf.iter().collect::<String>(),
+                total
+            );
+            break;
+        }
+    }
+    total
+}
@@ -0,0 +1,329 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::repl::Query;
+use crate::solution::{Answer, Result, Solution};
+use crate::util::input;
+
+// Some comments for future me. Using a Weak pointer to the parent prevents a
+// cyclical reference between a parent pointing at a child and the child
+// pointing at the parent. Because we allocate the memory for pointed at objects
+// on the heap with reference counted borrows, this causes us to allocate
+// infinite memory.
+//
+// This is resolved by making the parent pointer a weak reference,
+// see https://doc.rust-lang.org/book/ch15-06-reference-cycles.html?highlight=Weak.
+//
+// We make the parent a weak reference because if a parent has no children, we
+// do not want to drop it. However, if a child has no parent, we want to drop
+// it, because we should never visit it (unless our current pointed is in the
+// orphaned branch of the tree, in which case it will not drop until that
+// pointer moves out).
+#[derive(Debug)]
+struct Node {
+    parent: Option<Weak<RefCell<Node>>>,
+    children: Vec<Rc<RefCell<Node>>>,
+    name: String,
+    files: Vec<(usize, String)>,
+}
+
+impl Node {
+    /// Return accumulated size of all files stored directly in this directory
+    fn get_direct_filesize(&self) -> usize {
+        let mut accumulator = 0;
+        for file in self.files.iter() {
+            accumulator += file.0;
+        }
+        accumulator
+    }
+
+    /// Return file size of all files and all subdirectories of this directory
+    fn get_total_filesize(&self) -> usize {
+        if self.children.is_empty() {
+            self.get_direct_filesize()
+        } else {
+            let mut accumulator = self.get_direct_filesize();
+            for child in self.children.iter() {
+                accumulator += child.borrow().get_total_filesize();
+            }
+            accumulator
+        }
+    }
+}
+
+/// The directories and files seen in the terminal output, with a current directory that `cd`
+/// moves around.
+#[derive(Debug)]
+pub struct FileTree {
+    root: Rc<RefCell<Node>>,
+    curr_ptr: Option<Rc<RefCell<Node>>>,
+}
+
+impl FileTree {
+    /// A tree with nothing but the root directory, which is also the current directory.
+    pub fn empty() -> FileTree {
+        let root = Node {
+            parent: None,
+            children: vec![],
+            name: String::from("/"),
+            files: vec![],
+        };
+        let root = Rc::new(RefCell::new(root));
+        FileTree {
+            curr_ptr: Some(Rc::clone(&root)),
+            root,
+        }
+    }
+
+    /// Point at root directory.
+    pub fn go_home(&mut self) {
+        self.curr_ptr = Some(Rc::clone(&self.root));
+    }
+
+    /// Change directory pointed at to parent of currently pointed at directory.
+    pub fn move_out(&mut self) {
+        let current = Rc::clone(self.curr_ptr.as_ref().unwrap());
+        if current.borrow().name != "/" {
+            let parent = Weak::clone(current.borrow().parent.as_ref().unwrap());
+            self.curr_ptr = Some(parent.upgrade().unwrap());
+        }
+    }
+
+    /// Change directory being pointed at to subdirectory of currently pointed
+    /// at directory `dir`.
+    pub fn change_directory(&mut self, dir: String) {
+        let curr_node = Rc::clone(self.curr_ptr.as_ref().unwrap());
+        let mut assigned = false;
+        for child in curr_node.borrow_mut().children.iter() {
+            if child.borrow().name == dir {
+                self.curr_ptr = Some(Rc::clone(child));
+                assigned = true;
+                break;
+            }
+        }
+        // path not in children of this node, create new node
+        if !assigned {
+            let new_node = Rc::new(RefCell::new(Node {
+                parent: Some(Rc::downgrade(self.curr_ptr.as_ref().unwrap())),
+                children: vec![],
+                name: dir,
+                files: vec![],
+            }));
+            curr_node.borrow_mut().children.push(Rc::clone(&new_node));
+            self.curr_ptr = Some(Rc::clone(&new_node));
+        }
+    }
+
+    /// Push a file into the file buffer of the currenty pointed at directory
+    pub fn add_file(&mut self, file: (usize, String)) {
+        self.curr_ptr
+            .as_mut()
+            .unwrap()
+            .borrow_mut()
+            .files
+            .push(file);
+    }
+
+    /// Traverse the filetree, visiting each directory and marking down its
+    /// path (like `/a/e`) and size. This is mad inefficient because I redundantly calculate
+    /// the size of all subdirectories over and over as I walk down the tree,
+    /// but I'm so far in I can't be bothered to make this efficient right now.
+    pub fn traverse_and_store_dirsize(&self) -> Vec<(String, usize)> {
+        let mut out: Vec<(String, usize)> = vec![];
+        let mut queue: Vec<(Rc<RefCell<Node>>, String)> = vec![];
+        queue.push((Rc::clone(&self.root), String::from("/")));
+        while let Some((curr_node, path)) = queue.pop() {
+            for child in curr_node.borrow().children.iter() {
+                let child_path = match path.as_str() {
+                    "/" => format!("/{}", child.borrow().name),
+                    _ => format!("{}/{}", path, child.borrow().name),
+                };
+                queue.push((Rc::clone(child), child_path));
+            }
+            out.push((path, curr_node.borrow().get_total_filesize()));
+        }
+        out
+    }
+}
+
+struct Command<'a> {
+    cmd: &'a str,
+    arg: &'a str,
+    output: Vec<&'a str>,
+}
+
+/// Today is pretty interesting! Most of the challenge is again in parsing
+/// the input into an appropriate data structure. I think my strategy is going
+/// to be as follows:
+///
+///   - Initialize an empty tree structure
+///   - Parse commands line by line
+///   - On each `cd`, move through the tree, adding a new node if necessary
+///   - On each `ls`, read the files and associate them with the current node
+///
+/// Then I just have to traverse the tree and calculate what I need to.
+///
+/// My chipper attitude when writing the above comment was dashed on the rocky
+/// shores of the rust borrow checker. I am grown.
+pub fn file_tree(input: &str) -> Result<FileTree> {
+    let lineiter = input::lines(input);
+    // Init tree structure
+    let mut filetree = FileTree::empty();
+
+    // Parse all the input into a command stack
+    let mut commands: Vec<Command> = vec![];
+    for line in lineiter {
+        let temp = line.split(' ').collect::<Vec<&str>>();
+        if temp[0] == "$" {
+            // parse command
+            match temp[..] {
+                [_, "cd", arg] => commands.push(Command {
+                    cmd: "cd",
+                    arg,
+                    output: vec![],
+                }),
+                [_, "ls"] => commands.push(Command {
+                    cmd: "ls",
+                    arg: "",
+                    output: vec![],
+                }),
+                _ => return Err(AocError::at(input, line, "`$ cd DIR` or `$ ls`")),
+            }
+        } else {
+            // dump output of ls into current directory
+            match commands.last_mut() {
+                Some(last_command) => last_command.output.push(line),
+                None => return Err(AocError::at(input, line, "a command starting with `$`")),
+            }
+        }
+    }
+
+    if commands.is_empty() {
+        return Err(AocError::at_end(input, "a command starting with `$`"));
+    }
+
+    // Use command stack to construct filetree
+    for command in commands {
+        match command.cmd {
+            "cd" => {
+                if command.arg == "/" {
+                    filetree.go_home();
+                } else if command.arg == ".." {
+                    filetree.move_out();
+                } else {
+                    filetree.change_directory(command.arg.to_string());
+                }
+            }
+            "ls" => {
+                for line in command.output {
+                    match line.split_once(' ') {
+                        Some(("dir", _)) => continue,
+                        Some((size, name)) => {
+                            // parse ls and populate the files of this directory
+                            let size = parse_at(input, size, "a file size or `dir`")?;
+                            filetree.add_file((size, name.to_string()));
+                        }
+                        None => return Err(AocError::at(input, line, "`dir NAME` or `SIZE NAME`")),
+                    }
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(filetree)
+}
+
+/// Returns the path and total size of every directory, sorted by size.
+pub fn dir_sizes(input: &str) -> Result<Vec<(String, usize)>> {
+    // Recurse through file tree and store the size of each directory
+    let mut dir_and_sizes = file_tree(input)?.traverse_and_store_dirsize();
+    dir_and_sizes.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    Ok(dir_and_sizes)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    /// Path and total size of every directory, sorted by size.
+    type Input = Vec<(String, usize)>;
+
+    const PARAMS: &'static [Param] = &[DISK_SIZE, SPACE_NEEDED];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        dir_sizes(input)
+    }
+
+    fn part1(dir_and_sizes: &Self::Input) -> Answer {
+        part1(dir_and_sizes).into()
+    }
+
+    fn part2(dir_and_sizes: &Self::Input) -> Option<Answer> {
+        part2(dir_and_sizes).map(Into::into)
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![Query {
+            name: "size",
+            args: "PATH",
+            help: "the total size of a directory, like /a/e",
+            run: |dir_and_sizes, path| {
+                let path = path.trim_end_matches('/');
+                let path = if path.is_empty() { "/" } else { path };
+                dir_and_sizes
+                    .iter()
+                    .find(|(dir, _)| dir == path)
+                    .map(|(_, size)| size.to_string())
+                    .ok_or_else(|| format!("there is no directory {}", path))
+            },
+        }]
+    }
+}
+
+const DISK_SIZE: Param = Param {
+    day: 7,
+    name: "disk_size",
+    default: 70000000,
+    range: 1..=i64::MAX,
+    help: "the total space on the device",
+};
+
+const SPACE_NEEDED: Param = Param {
+    day: 7,
+    name: "space_needed",
+    default: 30000000,
+    range: 0..=i64::MAX,
+    help: "the unused space the update needs",
+};
+
+/// The accumulated size of all small directories
+pub fn part1(dir_and_sizes: &[(String, usize)]) -> usize {
+    let mut accumulator = 0;
+    for (_, size) in dir_and_sizes.iter() {
+        if size > &100000 {
+            break;
+        }
+        accumulator += size;
+    }
+    accumulator
+}
+
+/// The size of the smallest directory that frees up enough space for the update, or `None` if not
+/// even deleting everything frees up enough.
+pub fn part2(dir_and_sizes: &[(String, usize)]) -> Option<usize> {
+    // Now I need to find the smallest directory which is larger than the space
+    // the update needs less the current unused space
+    let curr_size = dir_and_sizes[dir_and_sizes.len() - 1].1;
+    let curr_unused_space = DISK_SIZE.get::<usize>().saturating_sub(curr_size);
+    let space_needed = SPACE_NEEDED
+        .get::<usize>()
+        .saturating_sub(curr_unused_space);
+    dir_and_sizes
+        .iter()
+        .map(|(_, size)| *size)
+        .find(|size| size > &space_needed)
+}
@@ -0,0 +1,110 @@
+use crate::solution::{Answer, Result, Solution};
+use crate::util::grid::{Grid, ORTHOGONAL};
+use crate::util::input;
+use crate::util::par::par_map;
+
+/// Iterate forwards and backwards through each row of the grid, storing the
+/// indices of trees that are visible from either end
+fn count_talltrees_leftright(
+    grid: &Grid<usize>,
+    mut preseen: Vec<(usize, usize)>,
+) -> Vec<(usize, usize)> {
+    let rowlen = grid.width();
+    let mut seen: Vec<(usize, usize)> = vec![];
+    seen.append(&mut preseen);
+    for (i, row) in grid.rows().enumerate() {
+        let mut largest: usize = 0;
+        for (j, val) in row.iter().enumerate() {
+            if val > &largest {
+                largest = *val;
+                if !seen.contains(&(i, j)) {
+                    seen.push((i, j));
+                }
+            }
+        }
+
+        largest = 0;
+        for (j, val) in row.iter().rev().enumerate() {
+            let k = rowlen - j - 1;
+            if val > &largest {
+                largest = *val;
+                if !seen.contains(&(i, k)) {
+                    seen.push((i, k));
+                }
+            }
+        }
+    }
+    seen
+}
+
+/// Returns the viewing distance of the tree at site (i, j). I simply
+/// brute force this by walking through the grid in each direction and adding
+/// up the number of seen trees.
+fn get_viewing_distance(grid: &Grid<usize>, i: usize, j: usize) -> usize {
+    let my_size = grid[(i, j)];
+    ORTHOGONAL
+        .iter()
+        .map(|&direction| {
+            // Trees on the edge see nothing in at least one direction, and so score 0
+            let mut accumulator = 0;
+            for pos in grid.ray((i, j), direction) {
+                accumulator += 1;
+                if grid[pos] >= my_size {
+                    break;
+                }
+            }
+            accumulator
+        })
+        .product()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    /// Tree heights, plus one.
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Option<Answer> {
+        Some(part2(grid).into())
+    }
+}
+
+fn read_grid(input: &str) -> Result<Grid<usize>> {
+    // Adding 1 to everything so I can use 0 as my default for the 'largest' comparator later.
+    Grid::parse(input, &input::lines(input), "a tree height", |c| {
+        c.to_digit(10).map(|height| height as usize + 1)
+    })
+}
+
+/// I spent a while thinking hard about clever ways to do this. It was a waste
+/// of time. The stupid way will work.
+pub fn part1(grid: &Grid<usize>) -> usize {
+    // Accumulate a list of co-ordinates of visible trees seen by looking
+    // along rows of the grid
+    let seen_trees = count_talltrees_leftright(grid, vec![]);
+    // Transpose the grid and do the same, doing some bookkeeping to avoid
+    // recounting trees we already saw.
+    let grid_t = grid.transpose();
+    let seen_trees_t: Vec<(usize, usize)> = seen_trees.iter().map(|(i, j)| (*j, *i)).collect();
+    let final_trees = count_talltrees_leftright(&grid_t, seen_trees_t);
+    // Number of seen trees
+    final_trees.len()
+}
+
+pub fn part2(grid: &Grid<usize>) -> usize {
+    // This is brute force, but it works. Every tree is scored separately, so they can be shared
+    // out between threads.
+    let positions: Vec<(usize, usize)> = grid.positions().collect();
+    par_map(&positions, |&(i, j)| get_viewing_distance(grid, i, j))
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+}
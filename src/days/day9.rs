@@ -0,0 +1,293 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use crate::error::{parse_at, AocError};
+use crate::params::Param;
+use crate::solution::{Answer, Result, Solution};
+use crate::stepper::{Simulation, Steppable, Stepper};
+use crate::util::geometry::{Heading, Point2};
+use crate::util::input;
+use crate::util::render::{Picture, Rgb, Style};
+
+#[derive(Debug, Clone)]
+pub struct Command {
+    heading: Heading,
+    distance: usize,
+}
+
+#[derive(Debug)]
+struct Segment {
+    pos: Point2,
+    prev: Option<Rc<RefCell<Segment>>>,
+}
+
+fn update_tail(tail_pos: Point2, head_pos: Point2) -> Point2 {
+    let diff = head_pos - tail_pos;
+    tail_pos + Point2::new(diff.x.clamp(-1, 1), diff.y.clamp(-1, 1))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    /// The motions of the head of the rope.
+    type Input = Vec<Command>;
+
+    const PARAMS: &'static [Param] = &[KNOTS];
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        read_commands(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Answer {
+        part1(commands).into()
+    }
+
+    fn part2(commands: &Self::Input) -> Option<Answer> {
+        Some(part2(commands).into())
+    }
+
+    /// The long rope of part 2, a step of the head at a time.
+    fn simulate(commands: &Self::Input) -> Option<Box<dyn Steppable>> {
+        Some(Box::new(Stepper::new(Rope {
+            commands: commands.clone(),
+            knots: KNOTS.get(),
+        })))
+    }
+}
+
+const KNOTS: Param = Param {
+    day: 9,
+    name: "knots",
+    default: 10,
+    range: 1..=i64::MAX,
+    help: "the knots in part 2's rope, counting the head and the tail",
+};
+
+/// A rope being pulled around by its head. The stepper doesn't need the linked list of
+/// [`part2`], so the knots are just kept in a vector, head first.
+struct Rope {
+    commands: Vec<Command>,
+    knots: usize,
+}
+
+#[derive(Clone)]
+struct RopeState {
+    knots: Vec<Point2>,
+    /// The command being carried out, and how many steps of it have been taken.
+    command: usize,
+    taken: usize,
+    visited: HashSet<Point2>,
+}
+
+impl Simulation for Rope {
+    type State = RopeState;
+
+    fn start(&self) -> RopeState {
+        RopeState {
+            knots: vec![Point2::default(); self.knots],
+            command: 0,
+            taken: 0,
+            visited: HashSet::from([Point2::default()]),
+        }
+    }
+
+    fn step(&self, state: &RopeState) -> Option<RopeState> {
+        let mut state = state.clone();
+        // skip any commands that have run out, or never had any steps
+        while state.taken == self.commands.get(state.command)?.distance {
+            state.command += 1;
+            state.taken = 0;
+        }
+        state.knots[0] += self.commands[state.command].heading.delta();
+        for i in 1..state.knots.len() {
+            if state.knots[i - 1].chebyshev(state.knots[i]) > 1 {
+                state.knots[i] = update_tail(state.knots[i], state.knots[i - 1]);
+            }
+        }
+        state.visited.insert(*state.knots.last().unwrap());
+        state.taken += 1;
+        Some(state)
+    }
+
+    /// The knots as the puzzle draws them, over the squares the tail has visited.
+    fn draw(&self, state: &RopeState) -> Picture {
+        let points = state.knots.iter().chain(&state.visited).copied();
+        Picture::from_points(points, Style::new('.', Rgb::DARK), |point| {
+            match state.knots.iter().position(|&knot| knot == point) {
+                Some(0) => Style::new('H', Rgb::RED),
+                Some(i) => Style::new(char::from_digit(i as u32, 36).unwrap(), Rgb::YELLOW),
+                None if point == Point2::default() => Style::new('s', Rgb::WHITE),
+                None => Style::new('#', Rgb::GREY),
+            }
+        })
+    }
+
+    fn values(&self, state: &RopeState) -> Vec<(&'static str, i64)> {
+        let (head, tail) = (state.knots[0], *state.knots.last().unwrap());
+        vec![
+            ("command", state.command as i64 + 1),
+            ("head_x", head.x),
+            ("head_y", head.y),
+            ("tail_x", tail.x),
+            ("tail_y", tail.y),
+            ("visited", state.visited.len() as i64),
+        ]
+    }
+}
+
+fn read_commands(input: &str) -> Result<Vec<Command>> {
+    let lineiter = input::lines(input);
+    // Read input into a command buffer
+    let mut commands: Vec<Command> = vec![];
+    for line in lineiter {
+        let (direction, distance) = line
+            .split_once(' ')
+            .ok_or_else(|| AocError::at(input, line, "a direction and a distance"))?;
+        let cmd = Command {
+            heading: match direction {
+                "U" => Heading::Up,
+                "R" => Heading::Right,
+                "D" => Heading::Down,
+                "L" => Heading::Left,
+                _ => return Err(AocError::at(input, direction, "U, R, D or L")),
+            },
+            distance: parse_at(input, distance, "a distance")?,
+        };
+        commands.push(cmd);
+    }
+    Ok(commands)
+}
+
+/// Two physical observations are essential to my solution today.
+///
+/// The first is
+/// that this problem exists in what I call the 'Dungeons and Dragons' metric,
+/// where diagonal moves are the same distance as cardinal moves. That is,
+///
+/// d(dx, dy) = max(dx, dy)
+///
+/// This is also known as the Chebyshev distance. We only ever move the tail
+/// of a segment if the Chebyshev distance between the head and the tail is
+/// greater than 1.
+///
+/// The second observation is that the update rule for the tail can be
+/// applied consistently for any separation by adding the displacement between
+/// the head and the tail with the length along each axis clamped to +-1
+///
+/// With these in hand, this becomes a simple book-keeping exercise.
+///
+/// I got to do another new fun data structure this week: a mutable Linked List.
+/// Being a simpler structure than the mutable Tree I used in day 7, I managed
+/// this quite easily using the things I learned there about the interior
+/// mutability pattern.
+///
+/// To be honest, I didn't need the linked list. I could have done this with
+/// just a vector. But the linked list was cool, and I wrote it at 3am, so
+/// that's good I guess.
+pub fn part1(commands: &[Command]) -> usize {
+    // We can model the lengths of rope with multiple segements (part 2) using
+    // a linked list. I'm going to use the interior mutability pattern for this
+    // because I need to let the LL be mutable. Each element in the list is an
+    // encapsulaed smart pointer i.e. Rc<RefCell<Segment>>, and each
+    // Segment holds another one of these pointers to its child segment
+    // (except the 'tail' segment which has no child).
+
+    // For Part 1 this is redundant overkill. We don't actually use the linked
+    // list at all because I can just explicitly index the tail and head
+    // variables to get the job done.
+
+    let tail = Rc::new(RefCell::new(Segment {
+        pos: Point2::default(),
+        prev: None,
+    }));
+    let head = Rc::new(RefCell::new(Segment {
+        pos: Point2::default(),
+        prev: Some(Rc::clone(&tail)),
+    }));
+
+    let mut visited_positions: Vec<Point2> = vec![Point2::default()];
+
+    for cmd in commands.iter() {
+        for _ in 0..cmd.distance {
+            head.borrow_mut().pos += cmd.heading.delta();
+            let dist = head.borrow().pos.chebyshev(tail.borrow().pos);
+            if dist > 1 {
+                // Use a let binding here so I don't have to immut borrow and
+                // mut borrow tail simultaneously.
+                let new_tail_pos = update_tail(tail.borrow().pos, head.borrow().pos);
+                if !visited_positions.contains(&new_tail_pos) {
+                    visited_positions.push(new_tail_pos);
+                }
+                tail.borrow_mut().pos = new_tail_pos;
+            };
+        }
+    }
+
+    visited_positions.len()
+}
+
+pub fn part2(commands: &[Command]) -> usize {
+    // For part 2 the linked list becomes more useful, after each move I can
+    // walk through the list and update the previous segment position of each
+    // segment after it is moved
+
+    // Initialize linked list with tail with no prev segment
+    let mut curr_seg: Rc<RefCell<Segment>> = Rc::new(RefCell::new(Segment {
+        pos: Point2::default(),
+        prev: None,
+    }));
+    let tail = Rc::clone(&curr_seg);
+    for _ in 1..KNOTS.get::<usize>() {
+        curr_seg = Rc::new(RefCell::new(Segment {
+            pos: Point2::default(),
+            prev: Some(Rc::clone(&curr_seg)),
+        }));
+    }
+    // Store an immutable reference to the head of the rope
+    let head = Rc::clone(&curr_seg);
+    // Now we have our linked list in memory and a pointer to the start
+    let mut visited_positions: Vec<Point2> = vec![Point2::default()];
+
+    for cmd in commands.iter() {
+        for _ in 0..cmd.distance {
+            // update head just as before
+            head.borrow_mut().pos += cmd.heading.delta();
+            // walk through list and move segments as appropriate
+            let mut curr_seg = Rc::clone(&head);
+            loop {
+                // We need a new reference count for curr_seg here to avoid
+                // some self-borrow-assign shenanigans
+                let curr_seg_clone = Rc::clone(&curr_seg);
+                match curr_seg_clone.borrow().prev.as_ref() {
+                    Some(prev_seg) => {
+                        let dist = curr_seg.borrow().pos.chebyshev(prev_seg.borrow().pos);
+
+                        if dist > 1 {
+                            // Use a let binding here so I don't have to immut borrow and
+                            // mut borrow tail simultaneously.
+                            let new_prev_pos =
+                                update_tail(prev_seg.borrow().pos, curr_seg.borrow().pos);
+                            prev_seg.borrow_mut().pos = new_prev_pos;
+                        } else {
+                            // We can break if any segment doesn't move, because
+                            // it's children then won't update
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+                // Now we're done with this clone we can use it to grab a ref
+                // to the previous segment to take as the current segment for
+                // the next iteration of the loop, and happily let the clone
+                // drop out of scope. Everyone is happy.
+                curr_seg = Rc::clone(curr_seg_clone.borrow().prev.as_ref().unwrap());
+            }
+            // check if we have a new pos for tail
+            let tail_pos = tail.borrow().pos;
+            if !visited_positions.contains(&tail_pos) {
+                visited_positions.push(tail_pos);
+            }
+        }
+    }
+
+    visited_positions.len()
+}
@@ -0,0 +1,275 @@
+//! Registry of every solved day, used by the `aoc` runner to dispatch to the right solver.
+//!
+//! Each day is also a public module, re-exported at the top of the crate as `aoc::day1` to
+//! `aoc::day25`, with its parsed input types and a typed `part1` and `part2` for using a day
+//! from other code. The [`Solution`] impl parses the input and turns the typed answers into
+//! [`Answer`]s.
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::params::Param;
+use crate::solution::{Answer, Part, Result, Solution};
+use crate::stepper::Steppable;
+use crate::util::render::Picture;
+
+/// A day's input after parsing, with its type hidden so that every day fits in one table.
+pub trait Parsed {
+    /// Answer `part`, or `None` if the day doesn't have that part.
+    fn solve(&self, part: Part) -> Option<Answer>;
+
+    /// Draw the puzzle, or `None` if the day has nothing to draw.
+    fn render(&self) -> Option<Picture>;
+
+    /// A stepper for the day's simulation, or `None` if the day isn't one.
+    fn simulate(&self) -> Option<Box<dyn Steppable>>;
+
+    /// The usage and help of each of the day's own commands for `aoc repl`.
+    fn queries(&self) -> Vec<(String, &'static str)>;
+
+    /// Run the day's own command `name` on the rest of the line, or `None` if it has no command
+    /// by that name.
+    fn query(&self, name: &str, args: &str) -> Option<std::result::Result<String, String>>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self, part: Part) -> Option<Answer> {
+        match part {
+            Part::One => Some(S::part1(&self.0)),
+            Part::Two => S::part2(&self.0),
+        }
+    }
+
+    fn render(&self) -> Option<Picture> {
+        S::render(&self.0)
+    }
+
+    fn simulate(&self) -> Option<Box<dyn Steppable>> {
+        S::simulate(&self.0)
+    }
+
+    fn queries(&self) -> Vec<(String, &'static str)> {
+        S::queries()
+            .iter()
+            .map(|query| (query.usage(), query.help))
+            .collect()
+    }
+
+    fn query(&self, name: &str, args: &str) -> Option<std::result::Result<String, String>> {
+        let query = S::queries().into_iter().find(|query| query.name == name)?;
+        if args.is_empty() != query.args.is_empty() {
+            return Some(Err(if args.is_empty() {
+                format!("{} needs {}", query.name, query.args)
+            } else {
+                format!("{} takes nothing after it", query.name)
+            }));
+        }
+        Some((query.run)(&self.0, args))
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(ParsedInput::<S>(S::parse(input)?)))
+}
+
+/// Entry point for a single day.
+pub struct Day {
+    pub number: usize,
+    pub title: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>>,
+    pub params: &'static [Param],
+}
+
+impl Day {
+    /// Parse `input`, tagging any error with this day's number.
+    pub fn parse_input(&self, input: &str) -> Result<Box<dyn Parsed>> {
+        (self.parse)(input).map_err(|err| err.on_day(self.number))
+    }
+}
+
+/// Every solved day, in order.
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        title: "Calorie Counting",
+        parse: parse::<day1::Day1>,
+        params: day1::Day1::PARAMS,
+    },
+    Day {
+        number: 2,
+        title: "Rock Paper Scissors",
+        parse: parse::<day2::Day2>,
+        params: day2::Day2::PARAMS,
+    },
+    Day {
+        number: 3,
+        title: "Rucksack Reorganization",
+        parse: parse::<day3::Day3>,
+        params: day3::Day3::PARAMS,
+    },
+    Day {
+        number: 4,
+        title: "Camp Cleanup",
+        parse: parse::<day4::Day4>,
+        params: day4::Day4::PARAMS,
+    },
+    Day {
+        number: 5,
+        title: "Supply Stacks",
+        parse: parse::<day5::Day5>,
+        params: day5::Day5::PARAMS,
+    },
+    Day {
+        number: 6,
+        title: "Tuning Trouble",
+        parse: parse::<day6::Day6>,
+        params: day6::Day6::PARAMS,
+    },
+    Day {
+        number: 7,
+        title: "No Space Left On Device",
+        parse: parse::<day7::Day7>,
+        params: day7::Day7::PARAMS,
+    },
+    Day {
+        number: 8,
+        title: "Treetop Tree House",
+        parse: parse::<day8::Day8>,
+        params: day8::Day8::PARAMS,
+    },
+    Day {
+        number: 9,
+        title: "Rope Bridge",
+        parse: parse::<day9::Day9>,
+        params: day9::Day9::PARAMS,
+    },
+    Day {
+        number: 10,
+        title: "Cathode-Ray Tube",
+        parse: parse::<day10::Day10>,
+        params: day10::Day10::PARAMS,
+    },
+    Day {
+        number: 11,
+        title: "Monkey in the Middle",
+        parse: parse::<day11::Day11>,
+        params: day11::Day11::PARAMS,
+    },
+    Day {
+        number: 12,
+        title: "Hill Climbing Algorithm",
+        parse: parse::<day12::Day12>,
+        params: day12::Day12::PARAMS,
+    },
+    Day {
+        number: 13,
+        title: "Distress Signal",
+        parse: parse::<day13::Day13>,
+        params: day13::Day13::PARAMS,
+    },
+    Day {
+        number: 14,
+        title: "Regolith Reservoir",
+        parse: parse::<day14::Day14>,
+        params: day14::Day14::PARAMS,
+    },
+    Day {
+        number: 15,
+        title: "Beacon Exclusion Zone",
+        parse: parse::<day15::Day15>,
+        params: day15::Day15::PARAMS,
+    },
+    Day {
+        number: 16,
+        title: "Proboscidea Volcanium",
+        parse: parse::<day16::Day16>,
+        params: day16::Day16::PARAMS,
+    },
+    Day {
+        number: 17,
+        title: "Pyroclastic Flow",
+        parse: parse::<day17::Day17>,
+        params: day17::Day17::PARAMS,
+    },
+    Day {
+        number: 18,
+        title: "Boiling Boulders",
+        parse: parse::<day18::Day18>,
+        params: day18::Day18::PARAMS,
+    },
+    Day {
+        number: 19,
+        title: "Not Enough Minerals",
+        parse: parse::<day19::Day19>,
+        params: day19::Day19::PARAMS,
+    },
+    Day {
+        number: 20,
+        title: "Grove Positioning System",
+        parse: parse::<day20::Day20>,
+        params: day20::Day20::PARAMS,
+    },
+    Day {
+        number: 21,
+        title: "Monkey Math",
+        parse: parse::<day21::Day21>,
+        params: day21::Day21::PARAMS,
+    },
+    Day {
+        number: 22,
+        title: "Monkey Map",
+        parse: parse::<day22::Day22>,
+        params: day22::Day22::PARAMS,
+    },
+    Day {
+        number: 23,
+        title: "Unstable Diffusion",
+        parse: parse::<day23::Day23>,
+        params: day23::Day23::PARAMS,
+    },
+    Day {
+        number: 24,
+        title: "Blizzard Basin",
+        parse: parse::<day24::Day24>,
+        params: day24::Day24::PARAMS,
+    },
+    Day {
+        number: 25,
+        title: "Full of Hot Air",
+        parse: parse::<day25::Day25>,
+        params: day25::Day25::PARAMS,
+    },
+];
+
+/// Look up a day by its number.
+pub fn get(number: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,90 @@
+//! The error every parser returns when its input isn't what the puzzle promised.
+//!
+//! Parsers work on slices of the raw input text (which is what [`crate::util::input`] hands
+//! out), so an error only needs the offending slice to work out which line and column it came
+//! from. The day is filled in afterwards by the registry, since a parser doesn't know which day
+//! it belongs to.
+
+use std::{error::Error, fmt, str::FromStr};
+
+/// Bad puzzle input, pinned to where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    /// The day whose input failed to parse, once known.
+    pub day: Option<usize>,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, counted in characters, of the offending text.
+    pub column: usize,
+    /// The offending text itself, empty if the input ended early.
+    pub text: String,
+    /// What the parser wanted to find instead.
+    pub expected: String,
+}
+
+impl AocError {
+    /// Report `found`, which must be a slice of `input`, where `expected` was wanted.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> AocError {
+        let start = input.as_ptr() as usize;
+        let offset = (found.as_ptr() as usize).wrapping_sub(start);
+        assert!(
+            offset <= input.len(),
+            "offending text must be a slice of the input"
+        );
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        AocError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Report that `input` ran out where `expected` was wanted.
+    pub fn at_end(input: &str, expected: impl Into<String>) -> AocError {
+        AocError::at(input, &input[input.len()..], expected)
+    }
+
+    /// Attach the day this error came from.
+    pub fn on_day(self, day: usize) -> AocError {
+        AocError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found end of input")
+        } else {
+            write!(f, "found `{}`", self.text)
+        }
+    }
+}
+
+impl Error for AocError {}
+
+/// Parse `token`, a slice of `input`, as a `T`, reporting where it sits if it isn't one.
+pub fn parse_at<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, AocError> {
+    token
+        .parse()
+        .map_err(|_| AocError::at(input, token, expected))
+}
+
+/// The character starting at byte `i` of `text`, as a slice of it so that it can be reported.
+pub fn char_at(text: &str, i: usize) -> &str {
+    let len = text[i..].chars().next().map_or(0, char::len_utf8);
+    &text[i..i + len]
+}
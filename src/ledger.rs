@@ -0,0 +1,307 @@
+//! A record of the answers given to the puzzle site and what it said about them, for
+//! `aoc answer` and for checking new answers against.
+//!
+//! When a guess is wrong the site often says whether it was too high or too low, which rules out
+//! a whole range of answers. The ledger keeps those hints, along with any answer confirmed as
+//! correct, in a small TOML file:
+//!
+//! ```toml
+//! [[guess]]
+//! day = 15
+//! part = 2
+//! value = "11374534948438"
+//! verdict = "too_low"
+//! ```
+//!
+//! The file is `answers.toml` in this crate, or wherever `AOC_LEDGER` says. Only the TOML this
+//! module writes is understood: tables of `[[guess]]` with integer and string values.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::solution::{Answer, Part};
+use crate::util::letters;
+
+/// Environment variable naming the ledger file.
+pub const LEDGER_VAR: &str = "AOC_LEDGER";
+
+/// What the puzzle site said about a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, with no hint about which way.
+    Wrong,
+    TooLow,
+    TooHigh,
+}
+
+impl Verdict {
+    const ALL: [(Verdict, &'static str); 4] = [
+        (Verdict::Correct, "correct"),
+        (Verdict::Wrong, "wrong"),
+        (Verdict::TooLow, "too_low"),
+        (Verdict::TooHigh, "too_high"),
+    ];
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, name) = Verdict::ALL.iter().find(|(v, _)| v == self).unwrap();
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verdict, String> {
+        Verdict::ALL
+            .into_iter()
+            .find(|&(_, name)| name == s)
+            .map(|(verdict, _)| verdict)
+            .ok_or_else(|| {
+                format!(
+                    "'{}' is not a verdict, expected correct, wrong, too_low or too_high",
+                    s
+                )
+            })
+    }
+}
+
+/// An answer given to the site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: usize,
+    pub part: Part,
+    /// As typed into the site, so letters for answers drawn in letters.
+    pub value: String,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for Guess {
+    /// What the site said about the guess, like `100 was too low`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let explanation = match self.verdict {
+            Verdict::Correct => "was confirmed as the answer",
+            Verdict::Wrong => "was wrong",
+            Verdict::TooLow => "was too low",
+            Verdict::TooHigh => "was too high",
+        };
+        write!(f, "{} {}", self.value, explanation)
+    }
+}
+
+/// Every guess recorded so far, in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub guesses: Vec<Guess>,
+}
+
+/// The value to compare with the guesses for `answer`: the answer itself, or the letters drawn
+/// in it. `None` if the letters can't be read.
+pub fn answer_value(answer: &Answer) -> Option<String> {
+    match answer {
+        Answer::Line(line) => Some(line.clone()),
+        Answer::Lines(lines) => letters::read(lines),
+    }
+}
+
+/// `value` as a TOML string.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// The TOML string that is the whole of `text`, without its quotes and escapes.
+fn unquote(text: &str) -> Option<String> {
+    let mut chars = text.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(chars.next().filter(|c| matches!(c, '\\' | '"'))?),
+            '"' => return None,
+            c => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+impl Ledger {
+    /// Read a ledger written by [`Ledger::to_toml`], reporting the line of anything else.
+    pub fn parse(text: &str) -> Result<Ledger, String> {
+        /// A guess with only some of its keys read so far.
+        #[derive(Default)]
+        struct Partial {
+            line: usize,
+            day: Option<usize>,
+            part: Option<Part>,
+            value: Option<String>,
+            verdict: Option<Verdict>,
+        }
+
+        fn finish(partial: Partial) -> Result<Guess, String> {
+            let missing = |key| format!("line {}: the guess has no {}", partial.line, key);
+            Ok(Guess {
+                day: partial.day.ok_or_else(|| missing("day"))?,
+                part: partial.part.ok_or_else(|| missing("part"))?,
+                value: partial.value.clone().ok_or_else(|| missing("value"))?,
+                verdict: partial.verdict.ok_or_else(|| missing("verdict"))?,
+            })
+        }
+
+        let mut ledger = Ledger::default();
+        let mut current: Option<Partial> = None;
+        for (i, line) in text.lines().enumerate() {
+            let number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line == "[[guess]]" {
+                if let Some(partial) = current.take() {
+                    ledger.guesses.push(finish(partial)?);
+                }
+                current = Some(Partial {
+                    line: number,
+                    ..Partial::default()
+                });
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {}", number, message);
+            let partial = current
+                .as_mut()
+                .ok_or_else(|| error("expected [[guess]]"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`"))?;
+            let value = value.trim();
+            match key.trim() {
+                "day" => {
+                    partial.day = Some(value.parse().map_err(|_| error("day should be a number"))?)
+                }
+                "part" => {
+                    partial.part = Some(match value {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return Err(error("part should be 1 or 2")),
+                    })
+                }
+                "value" => {
+                    partial.value =
+                        Some(unquote(value).ok_or_else(|| error("value should be a string"))?)
+                }
+                "verdict" => {
+                    let verdict =
+                        unquote(value).ok_or_else(|| error("verdict should be a string"))?;
+                    partial.verdict =
+                        Some(verdict.parse().map_err(|message: String| error(&message))?)
+                }
+                key => return Err(error(&format!("unknown key '{}'", key))),
+            }
+        }
+        if let Some(partial) = current {
+            ledger.guesses.push(finish(partial)?);
+        }
+        Ok(ledger)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml =
+            String::from("# Answers given to the puzzle site, kept by `aoc answer record`.\n");
+        for guess in &self.guesses {
+            toml.push_str(&format!(
+                "\n[[guess]]\nday = {}\npart = {}\nvalue = {}\nverdict = {}\n",
+                guess.day,
+                guess.part,
+                quote(&guess.value),
+                quote(&guess.verdict.to_string())
+            ));
+        }
+        toml
+    }
+
+    /// Read the ledger at `path`, which is empty if there is no file yet.
+    pub fn load(path: &Path) -> io::Result<Ledger> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ledger::parse(&text).map_err(|message| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), message),
+                )
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(io::Error::new(
+                err.kind(),
+                format!("{}: {}", path.display(), err),
+            )),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+    }
+
+    /// Every guess at a part, in the order they were made.
+    pub fn guesses(&self, day: usize, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part)
+    }
+
+    /// Add `guess`, or change the verdict if the same value has been guessed before. A second
+    /// correct answer to the same part is refused.
+    pub fn record(&mut self, guess: Guess) -> Result<(), String> {
+        if guess.verdict == Verdict::Correct {
+            if let Some(correct) = self
+                .guesses(guess.day, guess.part)
+                .find(|other| other.verdict == Verdict::Correct && other.value != guess.value)
+            {
+                return Err(format!(
+                    "day {} part {} already has a correct answer, {}",
+                    guess.day, guess.part, correct.value
+                ));
+            }
+        }
+        match self.guesses.iter_mut().find(|other| {
+            other.day == guess.day && other.part == guess.part && other.value == guess.value
+        }) {
+            Some(other) => other.verdict = guess.verdict,
+            None => self.guesses.push(guess),
+        }
+        Ok(())
+    }
+
+    /// The guesses made so far that rule out `value` as the answer to a part. Empty if nothing
+    /// rules it out. Too high and too low only rule out values that are numbers.
+    pub fn contradictions(&self, day: usize, part: Part, value: &str) -> Vec<&Guess> {
+        let number = value.parse::<i128>().ok();
+        self.guesses(day, part)
+            .filter(|guess| {
+                let guessed = guess.value.parse::<i128>().ok();
+                match guess.verdict {
+                    Verdict::Correct => guess.value != value,
+                    Verdict::Wrong => guess.value == value,
+                    Verdict::TooLow => {
+                        guess.value == value
+                            || matches!((number, guessed), (Some(n), Some(g)) if n <= g)
+                    }
+                    Verdict::TooHigh => {
+                        guess.value == value
+                            || matches!((number, guessed), (Some(n), Some(g)) if n >= g)
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Where the ledger is kept: `AOC_LEDGER`, or `answers.toml` in this crate.
+pub fn default_path() -> PathBuf {
+    match env::var_os(LEDGER_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml"),
+    }
+}
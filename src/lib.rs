@@ -1 +1,15 @@
-pub mod util {}
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod ledger;
+pub mod memory;
+pub mod params;
+pub mod repl;
+pub mod solution;
+pub mod stepper;
+pub mod util;
+
+pub use days::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20, day21,
+    day22, day23, day24, day25, day3, day4, day5, day6, day7, day8, day9,
+};
@@ -0,0 +1,114 @@
+//! Counting what a piece of work allocates, for the memory columns of `aoc run`.
+//!
+//! With the `count-allocs` feature, every allocation goes through [`Counting`], which passes it
+//! on to the system allocator and keeps a running count of allocations, bytes and live bytes.
+//! [`measure`] reads the counts either side of some work. The counts are shared by every thread,
+//! so work that other threads do for it (as the days using [`crate::util::par`] do) is counted
+//! too, and so is anything unrelated that happens at the same time.
+//!
+//! Without the feature nothing is counted and every [`Usage`] is zero.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Whether allocations are being counted.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting as it goes. A reallocation counts as a new allocation of the
+/// new size.
+pub struct Counting;
+
+impl Counting {
+    /// Count an allocation of `size` bytes, which replaced `freed` bytes.
+    fn record(size: usize, freed: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        // both are live until the old one is freed
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        LIVE.fetch_sub(freed, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Counting::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Counting::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            Counting::record(new_size, layout.size());
+        }
+        new
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// What some work allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// The most bytes live at once, over what was live before the work started.
+    pub peak: usize,
+}
+
+/// Run `f`, counting what it allocates. Measurements mustn't overlap, since each one starts the
+/// peak afresh.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let result = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, usage)
+}
+
+/// `bytes` in the biggest binary unit that keeps it at least 1, like `1.50 MiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,191 @@
+//! Named constants of the puzzles, which can be changed without rebuilding.
+//!
+//! Some of the numbers a day needs come from the puzzle text rather than the input, like the row
+//! day 15 looks at or the 30 minutes day 16 has, and the examples in the puzzles often use
+//! smaller ones. Each day declares these as [`Param`]s, with the values the real puzzle uses as
+//! defaults, and reads them as it solves. They can be changed in a small TOML file, `aoc.toml` in
+//! this crate or wherever `AOC_CONFIG` says:
+//!
+//! ```toml
+//! [day15]
+//! probe_row = 10
+//! search_max = 20
+//! ```
+//!
+//! or one at a time with the runner's `--set day15.probe_row=10`, which wins over the file. Only
+//! tables named after days, holding whole numbers, are understood, and each parameter only takes
+//! the values its day can solve with.
+
+use std::{
+    env, fmt, fs, io,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use crate::days;
+
+/// Environment variable naming the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// A number a day's puzzle is stated in terms of, like day 15's `probe_row`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Param {
+    pub day: usize,
+    pub name: &'static str,
+    /// The value the real puzzle uses.
+    pub default: i64,
+    /// The values the day can solve with, like at least one round.
+    pub range: RangeInclusive<i64>,
+    pub help: &'static str,
+}
+
+/// The values set so far, by day and name, latest last.
+static OVERRIDES: RwLock<Vec<(usize, &'static str, i64)>> = RwLock::new(vec![]);
+
+impl Param {
+    /// The value set most recently, or the default if there is none.
+    pub fn value(&self) -> i64 {
+        OVERRIDES
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|&&(day, name, _)| day == self.day && name == self.name)
+            .map_or(self.default, |&(_, _, value)| value)
+    }
+
+    /// `value` if it is in this parameter's range, or what the range is.
+    pub fn check(&self, value: i64) -> Result<i64, String> {
+        let (&min, &max) = (self.range.start(), self.range.end());
+        if self.range.contains(&value) {
+            Ok(value)
+        } else if max == i64::MAX {
+            Err(format!("{} must be at least {}, not {}", self, min, value))
+        } else if min == i64::MIN {
+            Err(format!("{} must be at most {}, not {}", self, max, value))
+        } else {
+            Err(format!(
+                "{} must be between {} and {}, not {}",
+                self, min, max, value
+            ))
+        }
+    }
+
+    /// The value as a `T`, which its range should make sure it fits in.
+    #[track_caller]
+    pub fn get<T: TryFrom<i64>>(&self) -> T {
+        let value = self.value();
+        T::try_from(value).unwrap_or_else(|_| {
+            panic!(
+                "{} = {} doesn't fit in {}",
+                self,
+                value,
+                std::any::type_name::<T>()
+            )
+        })
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{}.{}", self.day, self.name)
+    }
+}
+
+/// Every day's parameters, in day order.
+pub fn all() -> impl Iterator<Item = &'static Param> {
+    days::DAYS.iter().flat_map(|day| day.params)
+}
+
+/// The parameter called `key`, like `day15.probe_row`.
+pub fn find(key: &str) -> Result<&'static Param, String> {
+    all()
+        .find(|param| param.to_string() == key)
+        .ok_or_else(|| format!("there is no parameter called '{}'", key))
+}
+
+/// Read a `KEY=VALUE` assignment, like `day15.probe_row=10`.
+pub fn parse_assignment(text: &str) -> Result<(&'static Param, i64), String> {
+    let (key, value) = text
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, found '{}'", text))?;
+    let param = find(key.trim())?;
+    let value = value.trim();
+    let value = value
+        .parse()
+        .map_err(|_| format!("'{}' is not a whole number", value))?;
+    Ok((param, param.check(value)?))
+}
+
+/// Use `value` for `param` from now on. It should be one [`Param::check`] allows.
+pub fn set(param: &'static Param, value: i64) {
+    OVERRIDES
+        .write()
+        .unwrap()
+        .push((param.day, param.name, value));
+}
+
+/// Go back to the defaults for every parameter.
+pub fn reset() {
+    OVERRIDES.write().unwrap().clear();
+}
+
+/// Read the assignments in a config file, reporting the line of anything else.
+pub fn parse_config(text: &str) -> Result<Vec<(&'static Param, i64)>, String> {
+    let mut assignments = vec![];
+    let mut day = None;
+    for (i, line) in text.lines().enumerate() {
+        let error = |message: &str| format!("line {}: {}", i + 1, message);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(table) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            let number = table
+                .strip_prefix("day")
+                .and_then(|number| number.parse::<usize>().ok())
+                .filter(|&number| days::get(number).is_some())
+                .ok_or_else(|| error(&format!("'{}' is not a solved day", table)))?;
+            day = Some(number);
+            continue;
+        }
+        let day = day.ok_or_else(|| error("expected a table like [day15]"))?;
+        let (param, value) =
+            parse_assignment(&format!("day{}.{}", day, line)).map_err(|message| error(&message))?;
+        assignments.push((param, value));
+    }
+    Ok(assignments)
+}
+
+/// Set every parameter the config file at `path` sets. No file sets nothing.
+pub fn load(path: &Path) -> io::Result<()> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => {
+            return Err(io::Error::new(
+                err.kind(),
+                format!("{}: {}", path.display(), err),
+            ))
+        }
+    };
+    let assignments = parse_config(&text).map_err(|message| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), message),
+        )
+    })?;
+    for (param, value) in assignments {
+        set(param, value);
+    }
+    Ok(())
+}
+
+/// Where the config file is: `AOC_CONFIG`, or `aoc.toml` in this crate.
+pub fn default_path() -> PathBuf {
+    match env::var_os(CONFIG_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml"),
+    }
+}
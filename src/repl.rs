@@ -0,0 +1,195 @@
+//! Poking at a day's parsed input, for `aoc repl`.
+//!
+//! The input is parsed once, then [`session`] reads commands one per line:
+//!
+//! - `1` or `2` solves a part again, and `run` solves both, with the time each took
+//! - `show` prints a picture of the puzzle, for the days that have one
+//! - `history` lists the commands so far, `!N` runs the Nth again and `!!` the last
+//! - `h` lists every command, including the day's own
+//! - `q` quits, as does the end of the input
+//!
+//! A day adds its own commands by returning [`Query`]s from
+//! [`Solution::queries`](crate::solution::Solution::queries), like `size /a/e` on day 7 to look
+//! up the size of a directory.
+
+use std::{
+    io::{self, BufRead, Write},
+    panic::{self, AssertUnwindSafe},
+    time::Instant,
+};
+
+use crate::days::Parsed;
+use crate::solution::{Answer, Part};
+
+/// A command for looking inside a day's parsed input.
+pub struct Query<I> {
+    pub name: &'static str,
+    /// What follows the name, like `PATH`, or empty if nothing does.
+    pub args: &'static str,
+    pub help: &'static str,
+    /// Answer the query for the rest of the line after the name, which is only empty if `args`
+    /// is, or explain what is wrong with it.
+    pub run: fn(&I, &str) -> Result<String, String>,
+}
+
+impl<I> Query<I> {
+    /// The name and what follows it, as shown in the help.
+    pub fn usage(&self) -> String {
+        if self.args.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{} {}", self.name, self.args)
+        }
+    }
+}
+
+const HELP: &str = "\
+1, 2              solve a part again
+run               solve both parts again
+show              print a picture of the puzzle
+history           list the commands so far
+!N                run the Nth command again
+!!                run the last command again
+h                 list the commands
+q                 quit";
+
+/// One line of input to a session.
+enum Command<'a> {
+    Solve(Vec<Part>),
+    Show,
+    History,
+    /// Run the command at this index in the history again.
+    Recall(usize),
+    Help,
+    Quit,
+    /// One of the day's own commands, and the rest of the line.
+    Query(&'a str, &'a str),
+}
+
+fn parse_command<'a>(line: &'a str, history: &[String]) -> Result<Command<'a>, String> {
+    let (word, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+    match word {
+        "1" => Ok(Command::Solve(vec![Part::One])),
+        "2" => Ok(Command::Solve(vec![Part::Two])),
+        "run" => Ok(Command::Solve(vec![Part::One, Part::Two])),
+        "show" => Ok(Command::Show),
+        "history" => Ok(Command::History),
+        "!!" if history.is_empty() => Err(String::from("there are no commands to run again")),
+        "!!" => Ok(Command::Recall(history.len() - 1)),
+        recall if recall.starts_with('!') => match recall[1..].parse::<usize>() {
+            Ok(n) if (1..=history.len()).contains(&n) => Ok(Command::Recall(n - 1)),
+            _ => Err(format!(
+                "'{}' is not in the history, which has {} commands",
+                &recall[1..],
+                history.len()
+            )),
+        },
+        "h" | "help" | "?" => Ok(Command::Help),
+        "q" | "quit" => Ok(Command::Quit),
+        name => Ok(Command::Query(name, rest)),
+    }
+}
+
+/// Run `f`, catching any panic so that one bad command doesn't end the session.
+fn guarded<T>(f: impl FnOnce() -> T) -> Option<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).ok()
+}
+
+/// Solve `part` and write the answer, or why there isn't one.
+fn solve(parsed: &dyn Parsed, part: Part, output: &mut impl Write) -> io::Result<()> {
+    let start = Instant::now();
+    let answer = guarded(|| parsed.solve(part));
+    let elapsed = start.elapsed();
+    match answer {
+        Some(Some(Answer::Line(line))) => {
+            writeln!(output, "Part {}: {}  ({:.2?})", part, line, elapsed)
+        }
+        Some(Some(Answer::Lines(lines))) => {
+            writeln!(output, "Part {}:  ({:.2?})", part, elapsed)?;
+            for line in lines {
+                writeln!(output, "{}", line)?;
+            }
+            Ok(())
+        }
+        Some(None) => writeln!(output, "error: there is no part {}", part),
+        None => writeln!(output, "error: part {} panicked", part),
+    }
+}
+
+/// Read commands from `input` until it runs out or says to quit, writing what happens to
+/// `output`. With `prompt`, ask for each command, for when a person is typing them; with `ansi`,
+/// print pictures in colour.
+pub fn session(
+    parsed: &dyn Parsed,
+    input: impl BufRead,
+    mut output: impl Write,
+    prompt: bool,
+    ansi: bool,
+) -> io::Result<()> {
+    let mut history: Vec<String> = vec![];
+    let mut lines = input.lines();
+    loop {
+        if prompt {
+            write!(output, "> ")?;
+            output.flush()?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let mut line = line?.trim().to_string();
+        if line.is_empty() {
+            continue;
+        }
+        if let Ok(Command::Recall(i)) = parse_command(&line, &history) {
+            line = history[i].clone();
+            writeln!(output, "{}", line)?;
+        }
+        let command = match parse_command(&line, &history) {
+            Ok(command) => command,
+            Err(message) => {
+                writeln!(output, "error: {}", message)?;
+                continue;
+            }
+        };
+        match command {
+            Command::Solve(parts) => {
+                for part in parts {
+                    solve(parsed, part, &mut output)?;
+                }
+            }
+            Command::Show => match parsed.render() {
+                Some(picture) => {
+                    let text = if ansi { picture.ansi() } else { picture.text() };
+                    writeln!(output, "{}", text)?;
+                }
+                None => writeln!(output, "error: this day has nothing to show")?,
+            },
+            Command::History => {
+                for (i, line) in history.iter().enumerate() {
+                    writeln!(output, "{:>4}  {}", i + 1, line)?;
+                }
+            }
+            // the recalled command has already been put in its place
+            Command::Recall(_) => unreachable!(),
+            Command::Help => {
+                writeln!(output, "{}", HELP)?;
+                for (usage, help) in parsed.queries() {
+                    writeln!(output, "{:<18}{}", usage, help)?;
+                }
+            }
+            Command::Quit => break,
+            Command::Query(name, args) => match guarded(|| parsed.query(name, args)) {
+                Some(Some(Ok(reply))) => writeln!(output, "{}", reply)?,
+                Some(Some(Err(message))) => writeln!(output, "error: {}", message)?,
+                Some(None) => {
+                    writeln!(output, "error: unknown command '{}', try h for help", name)?;
+                    continue;
+                }
+                None => writeln!(output, "error: {} panicked", name)?,
+            },
+        }
+        history.push(line);
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! The shape every day's solver takes: parse the input once, then solve each part from the parsed
+//! form. Keeping the stages apart lets the runner time them separately and lets anything else
+//! reuse a solver without going through stdout.
+
+use std::fmt;
+
+use crate::error::AocError;
+use crate::params::Param;
+use crate::repl::Query;
+use crate::stepper::Steppable;
+use crate::util::bigint::BigInt;
+use crate::util::render::Picture;
+
+/// Result of parsing a day's input.
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Anything that fits on one line, which is nearly every answer.
+    Line(String),
+    /// Answers drawn as a picture, like the letters on day 10's CRT.
+    Lines(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Line(line) => write!(f, "{}", line),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_display {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::Line(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from_display!(usize, isize, u32, i32, u64, i64, BigInt, String, &str);
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Answer {
+        Answer::Lines(lines)
+    }
+}
+
+/// A single day's puzzle.
+pub trait Solution {
+    /// The puzzle input once parsed.
+    type Input;
+
+    /// The numbers the puzzle is stated in terms of, which can be changed to solve the examples.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// Every day but the last has a second part; day 25 leaves this as `None`.
+    fn part2(_input: &Self::Input) -> Option<Answer> {
+        None
+    }
+
+    /// A picture of the puzzle, for the days where there is something worth looking at.
+    fn render(_input: &Self::Input) -> Option<Picture> {
+        None
+    }
+
+    /// The puzzle as a simulation to step through, for the days that are one.
+    fn simulate(_input: &Self::Input) -> Option<Box<dyn Steppable>> {
+        None
+    }
+
+    /// The day's own commands for `aoc repl`, for looking inside the parsed input.
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![]
+    }
+}
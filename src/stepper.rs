@@ -0,0 +1,404 @@
+//! Stepping through a day's simulation forwards and backwards, for `aoc step`.
+//!
+//! A day exposes its simulation by implementing [`Simulation`]: where it starts, how to take a
+//! single step, and how to show a state. A [`Stepper`] drives it and keeps the history needed to
+//! go back, as a checkpoint every [`CHECKPOINT_INTERVAL`] steps that later states are replayed
+//! from, so that a long simulation doesn't have to hold on to every state it passes through.
+//!
+//! [`session`] reads commands one per line and reports on the state after each:
+//!
+//! - `n [COUNT]` steps forward, once by default
+//! - `b [COUNT]` steps back
+//! - `g STEP` goes to a step
+//! - `p` prints a picture of the state
+//! - `w NAME OP VALUE` stops stepping forward as soon as a condition on one of the values shown
+//!   with each state becomes true, like `w units >= 20`; `w` alone lists the watches and
+//!   `w clear` removes them
+//! - `q` quits, as does the end of the input
+//!
+//! An empty line repeats the last `n` or `b`.
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::util::render::Picture;
+
+/// A day's puzzle as a sequence of states, one step apart.
+pub trait Simulation {
+    type State: Clone;
+
+    fn start(&self) -> Self::State;
+
+    /// The state one step after `state`, or `None` if the simulation has finished.
+    fn step(&self, state: &Self::State) -> Option<Self::State>;
+
+    fn draw(&self, state: &Self::State) -> Picture;
+
+    /// Named quantities describing `state`, shown after every move and used by watches.
+    fn values(&self, state: &Self::State) -> Vec<(&'static str, i64)>;
+}
+
+/// How often a [`Stepper`] keeps a copy of the state to go back to.
+pub const CHECKPOINT_INTERVAL: usize = 100;
+
+/// Why a [`Steppable::seek`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// Got to the step asked for.
+    Reached,
+    /// The simulation finished first.
+    Finished,
+    /// The watch with this index became true first.
+    Watch(usize),
+}
+
+/// A [`Stepper`] with the type of its states hidden, so that every day's can be driven by the
+/// same [`session`].
+pub trait Steppable {
+    /// The current step, counting the start as step 0.
+    fn position(&self) -> usize;
+
+    /// Go to step `target`. Going forwards, stop early if the simulation finishes or as soon as
+    /// one of `watches` becomes true.
+    fn seek(&mut self, target: usize, watches: &[Watch]) -> Stop;
+
+    fn draw(&self) -> Picture;
+
+    fn values(&self) -> Vec<(&'static str, i64)>;
+}
+
+/// Drives a [`Simulation`], remembering enough of where it has been to go back.
+pub struct Stepper<S: Simulation> {
+    simulation: S,
+    /// The state at every multiple of `CHECKPOINT_INTERVAL` reached so far.
+    checkpoints: Vec<S::State>,
+    state: S::State,
+    position: usize,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(simulation: S) -> Stepper<S> {
+        let state = simulation.start();
+        Stepper {
+            checkpoints: vec![state.clone()],
+            state,
+            position: 0,
+            simulation,
+        }
+    }
+
+    /// Take one step, returning false if the simulation has already finished.
+    fn forward(&mut self) -> bool {
+        let Some(next) = self.simulation.step(&self.state) else {
+            return false;
+        };
+        self.state = next;
+        self.position += 1;
+        if self.position == self.checkpoints.len() * CHECKPOINT_INTERVAL {
+            self.checkpoints.push(self.state.clone());
+        }
+        true
+    }
+}
+
+impl<S: Simulation> Steppable for Stepper<S> {
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    fn seek(&mut self, target: usize, watches: &[Watch]) -> Stop {
+        if target < self.position {
+            // replay from the last checkpoint at or before the target, which we know gets there
+            let checkpoint = target / CHECKPOINT_INTERVAL;
+            self.state = self.checkpoints[checkpoint].clone();
+            self.position = checkpoint * CHECKPOINT_INTERVAL;
+            while self.position < target {
+                self.forward();
+            }
+            return Stop::Reached;
+        }
+        let mut held: Vec<bool> = {
+            let values = self.values();
+            watches.iter().map(|watch| watch.holds(&values)).collect()
+        };
+        while self.position < target {
+            if !self.forward() {
+                return Stop::Finished;
+            }
+            if watches.is_empty() {
+                continue;
+            }
+            let values = self.values();
+            for (i, watch) in watches.iter().enumerate() {
+                let holds = watch.holds(&values);
+                if holds && !held[i] {
+                    return Stop::Watch(i);
+                }
+                held[i] = holds;
+            }
+        }
+        Stop::Reached
+    }
+
+    fn draw(&self) -> Picture {
+        self.simulation.draw(&self.state)
+    }
+
+    fn values(&self) -> Vec<(&'static str, i64)> {
+        self.simulation.values(&self.state)
+    }
+}
+
+/// How a watch compares a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    const ALL: [(Comparison, &'static str); 6] = [
+        (Comparison::Eq, "=="),
+        (Comparison::Ne, "!="),
+        (Comparison::Lt, "<"),
+        (Comparison::Le, "<="),
+        (Comparison::Gt, ">"),
+        (Comparison::Ge, ">="),
+    ];
+
+    fn compare(self, a: i64, b: i64) -> bool {
+        match self {
+            Comparison::Eq => a == b,
+            Comparison::Ne => a != b,
+            Comparison::Lt => a < b,
+            Comparison::Le => a <= b,
+            Comparison::Gt => a > b,
+            Comparison::Ge => a >= b,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, symbol) = Comparison::ALL.iter().find(|(op, _)| op == self).unwrap();
+        write!(f, "{}", symbol)
+    }
+}
+
+/// A condition on one of a simulation's values, like `units >= 20`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Watch {
+    pub name: String,
+    pub comparison: Comparison,
+    pub value: i64,
+}
+
+impl Watch {
+    /// Whether the condition holds for `values`. A value that isn't there never matches.
+    pub fn holds(&self, values: &[(&str, i64)]) -> bool {
+        values
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .is_some_and(|&(_, value)| self.comparison.compare(value, self.value))
+    }
+}
+
+impl FromStr for Watch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Watch, String> {
+        let [name, comparison, value] = s.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(format!("expected NAME OP VALUE, found '{}'", s));
+        };
+        let (comparison, _) = Comparison::ALL
+            .into_iter()
+            .find(|&(_, symbol)| symbol == comparison)
+            .ok_or_else(|| {
+                format!(
+                    "'{}' is not a comparison, expected ==, !=, <, <=, > or >=",
+                    comparison
+                )
+            })?;
+        let value = value
+            .parse()
+            .map_err(|_| format!("'{}' is not a number", value))?;
+        Ok(Watch {
+            name: name.to_string(),
+            comparison,
+            value,
+        })
+    }
+}
+
+impl fmt::Display for Watch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.name, self.comparison, self.value)
+    }
+}
+
+const HELP: &str = "\
+n [COUNT]         step forward
+b [COUNT]         step back
+g STEP            go to a step
+p                 print the state
+w NAME OP VALUE   stop stepping forward when a value meets a condition
+w                 list the watches
+w clear           remove the watches
+q                 quit
+An empty line repeats the last n or b.";
+
+/// One line of input to a session.
+enum Command {
+    Next(usize),
+    Back(usize),
+    Goto(usize),
+    Print,
+    Watch(Watch),
+    ListWatches,
+    ClearWatches,
+    Help,
+    Quit,
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let (word, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+    let count = |default: Option<usize>| match (rest, default) {
+        ("", Some(default)) => Ok(default),
+        ("", None) => Err(format!("{} needs a step", word)),
+        (count, _) => count
+            .parse()
+            .map_err(|_| format!("'{}' is not a number of steps", count)),
+    };
+    match word {
+        "n" | "next" => Ok(Command::Next(count(Some(1))?)),
+        "b" | "back" => Ok(Command::Back(count(Some(1))?)),
+        "g" | "goto" => Ok(Command::Goto(count(None)?)),
+        "p" | "print" => Ok(Command::Print),
+        "w" | "watch" => match rest {
+            "" => Ok(Command::ListWatches),
+            "clear" => Ok(Command::ClearWatches),
+            watch => Ok(Command::Watch(watch.parse()?)),
+        },
+        "h" | "help" | "?" => Ok(Command::Help),
+        "q" | "quit" => Ok(Command::Quit),
+        _ => Err(format!("unknown command '{}', try h for help", word)),
+    }
+}
+
+/// The step and every value, on one line.
+fn status(stepper: &dyn Steppable) -> String {
+    let mut status = format!("step {}", stepper.position());
+    for (name, value) in stepper.values() {
+        status.push_str(&format!("  {}={}", name, value));
+    }
+    status
+}
+
+/// Read commands from `input` until it runs out or says to quit, writing what happens to
+/// `output`. With `prompt`, ask for each command, for when a person is typing them; with `ansi`,
+/// print pictures in colour.
+pub fn session(
+    stepper: &mut dyn Steppable,
+    input: impl BufRead,
+    mut output: impl Write,
+    prompt: bool,
+    ansi: bool,
+) -> io::Result<()> {
+    let mut watches: Vec<Watch> = vec![];
+    // the last `n` or `b`, for an empty line to repeat
+    let mut repeat: Option<String> = None;
+    writeln!(output, "{}", status(stepper))?;
+    let mut lines = input.lines();
+    loop {
+        if prompt {
+            write!(output, "> ")?;
+            output.flush()?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let mut line = line?.trim().to_string();
+        if line.is_empty() {
+            match &repeat {
+                Some(last) => line = last.clone(),
+                None => continue,
+            }
+        }
+        let command = match parse_command(&line) {
+            Ok(command) => command,
+            Err(message) => {
+                writeln!(output, "error: {}", message)?;
+                continue;
+            }
+        };
+        let position = stepper.position();
+        let target = match command {
+            Command::Next(count) => {
+                repeat = Some(line);
+                position.saturating_add(count)
+            }
+            Command::Back(count) => {
+                repeat = Some(line);
+                position.saturating_sub(count)
+            }
+            Command::Goto(step) => step,
+            Command::Print => {
+                let picture = stepper.draw();
+                let text = if ansi { picture.ansi() } else { picture.text() };
+                writeln!(output, "{}", text)?;
+                continue;
+            }
+            Command::Watch(watch) => {
+                let values = stepper.values();
+                if values.iter().all(|(name, _)| *name != watch.name) {
+                    let names: Vec<&str> = values.iter().map(|(name, _)| *name).collect();
+                    writeln!(
+                        output,
+                        "error: there is no value called '{}', only {}",
+                        watch.name,
+                        names.join(", ")
+                    )?;
+                } else {
+                    writeln!(output, "watch {}: {}", watches.len() + 1, watch)?;
+                    watches.push(watch);
+                }
+                continue;
+            }
+            Command::ListWatches => {
+                for (i, watch) in watches.iter().enumerate() {
+                    writeln!(output, "watch {}: {}", i + 1, watch)?;
+                }
+                continue;
+            }
+            Command::ClearWatches => {
+                watches.clear();
+                continue;
+            }
+            Command::Help => {
+                writeln!(output, "{}", HELP)?;
+                continue;
+            }
+            Command::Quit => break,
+        };
+        match stepper.seek(target, &watches) {
+            Stop::Reached => writeln!(output, "{}", status(stepper))?,
+            Stop::Finished => writeln!(output, "{}  (finished)", status(stepper))?,
+            Stop::Watch(i) => writeln!(
+                output,
+                "{}  (watch {}: {})",
+                status(stepper),
+                i + 1,
+                watches[i]
+            )?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,602 @@
+//! Whole numbers of any size, for the days whose sums can outgrow 64 bits.
+//!
+//! A [`BigInt`] is a sign and a magnitude held as base 2^32 limbs, least significant first, with
+//! no leading zero limbs, so that every number has just one form and the derived equality and
+//! hashing work. Long products switch from schoolbook to Karatsuba multiplication, and division
+//! is Knuth's algorithm D, truncating towards zero like Rust's integers.
+//!
+//! Exact arithmetic is much slower than machine integers, so days only use it when asked to:
+//! `AOC_EXACT`, or the runner's `--exact` flag, turns it on. Days written against [`Integer`] can
+//! then do the same sums in either.
+
+use std::{
+    cmp::Ordering,
+    env, fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering as AtomicOrdering},
+};
+
+use crate::util::checked;
+
+/// Environment variable that turns on exact arithmetic when set to anything but `0`.
+pub const EXACT_VAR: &str = "AOC_EXACT";
+
+/// Limbs in the shorter factor below which schoolbook multiplication beats Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// The largest power of ten that fits in a limb, for converting to and from decimal.
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+/// A signed integer of any size.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Never set for zero.
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+/// Drop leading zero limbs.
+fn trim(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        let total = limb as u64 + b.get(i).copied().unwrap_or(0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    if carry > 0 {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+/// `a - b`, where `a` is at least `b`.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        let total = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        difference.push(total as u32);
+        borrow = (total < 0) as i64;
+    }
+    debug_assert!(borrow == 0, "subtracted a bigger magnitude");
+    trim(&mut difference);
+    difference
+}
+
+fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let total = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    trim(&mut product);
+    product
+}
+
+/// `limbs` shifted up by `shift` whole limbs.
+fn shifted(limbs: &[u32], shift: usize) -> Vec<u32> {
+    if limbs.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![0; shift];
+    result.extend_from_slice(limbs);
+    result
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    // a = a1 * B^half + a0, and likewise b, so that a * b needs only three half-size products
+    let half = a.len().max(b.len()) / 2;
+    let split = |x: &[u32]| {
+        let (low, high) = x.split_at(half.min(x.len()));
+        let mut low = low.to_vec();
+        trim(&mut low);
+        (low, high.to_vec())
+    };
+    let ((a0, a1), (b0, b1)) = (split(a), split(b));
+    let low = mul_mag(&a0, &b0);
+    let high = mul_mag(&a1, &b1);
+    let middle = mul_mag(&add_mag(&a0, &a1), &add_mag(&b0, &b1));
+    let middle = sub_mag(&sub_mag(&middle, &low), &high);
+    let mut product = add_mag(
+        &add_mag(&shifted(&high, 2 * half), &shifted(&middle, half)),
+        &low,
+    );
+    trim(&mut product);
+    product
+}
+
+/// `a / d` and `a % d` for a single-limb `d`.
+fn divrem_limb(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0; a.len()];
+    let mut remainder = 0u64;
+    for i in (0..a.len()).rev() {
+        let current = (remainder << 32) | a[i] as u64;
+        quotient[i] = (current / d as u64) as u32;
+        remainder = current % d as u64;
+    }
+    trim(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+/// `limbs` shifted up by `bits`, less than a limb, with room for one more limb on top.
+fn shl_bits(limbs: &[u32], bits: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+    for &limb in limbs {
+        result.push((limb << bits) | carry);
+        carry = if bits == 0 { 0 } else { limb >> (32 - bits) };
+    }
+    result.push(carry);
+    result
+}
+
+/// `a / b` and `a % b`, by Knuth's algorithm D. `b` mustn't be zero.
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if cmp_mag(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, remainder) = divrem_limb(a, b[0]);
+        let mut remainder = vec![remainder];
+        trim(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    // normalise so that the top limb of the divisor has its top bit set, which keeps each guess
+    // at a quotient limb within two of the truth
+    let shift = b[b.len() - 1].leading_zeros();
+    let mut u = shl_bits(a, shift);
+    let mut v = shl_bits(b, shift);
+    v.pop();
+    let n = v.len();
+    let (top, next) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut quotient = vec![0; u.len() - n];
+    for j in (0..u.len() - n).rev() {
+        let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut guess = numerator / top;
+        let mut rest = numerator % top;
+        while guess > u32::MAX as u64 || guess * next > ((rest << 32) | u[j + n - 2] as u64) {
+            guess -= 1;
+            rest += top;
+            if rest > u32::MAX as u64 {
+                break;
+            }
+        }
+
+        // subtract guess * v from the current window of u
+        let mut borrow = 0;
+        let mut carry = 0;
+        for i in 0..n {
+            let product = guess * v[i] as u64 + carry;
+            carry = product >> 32;
+            let total = u[i + j] as i64 - borrow - (product & u32::MAX as u64) as i64;
+            u[i + j] = total as u32;
+            borrow = (total < 0) as i64;
+        }
+        let total = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = total as u32;
+
+        // the guess was one too big, so add one v back
+        if total < 0 {
+            guess -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = guess as u32;
+    }
+
+    let mut remainder: Vec<u32> = (0..n)
+        .map(|i| {
+            let high = if shift == 0 || i + 1 == n {
+                0
+            } else {
+                u[i + 1] << (32 - shift)
+            };
+            (u[i] >> shift) | high
+        })
+        .collect();
+    trim(&mut quotient);
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> BigInt {
+        trim(&mut limbs);
+        BigInt {
+            negative: negative && !limbs.is_empty(),
+            limbs,
+        }
+    }
+
+    pub fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.limbs.clone())
+    }
+
+    /// The number as an `i128`, if it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .limbs
+            .iter()
+            .rev()
+            .fold(0u128, |total, &limb| (total << 32) | limb as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    fn from_u128(negative: bool, mut magnitude: u128) -> BigInt {
+        let mut limbs = vec![];
+        while magnitude > 0 {
+            limbs.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        BigInt::from_parts(negative, limbs)
+    }
+
+    /// Quotient and remainder at once, truncating towards zero: the remainder has the sign of
+    /// `self`. Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = divrem_mag(&self.limbs, &rhs.limbs);
+        (
+            BigInt::from_parts(self.negative != rhs.negative, quotient),
+            BigInt::from_parts(self.negative, remainder),
+        )
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut result = BigInt::from(1);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> BigInt {
+                    BigInt::from_u128(value < 0, (value as i128).unsigned_abs())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> BigInt {
+                    BigInt::from_u128(false, value as u128)
+                }
+            }
+        )*
+    };
+}
+
+from_signed!(i8, i16, i32, i64, i128, isize);
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.limbs, &other.limbs),
+            (true, true) => cmp_mag(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.limbs.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.limbs)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.limbs, &rhs.limbs));
+        }
+        // opposite signs: the bigger magnitude wins
+        match cmp_mag(&self.limbs, &rhs.limbs) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_mag(&rhs.limbs, &self.limbs)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.limbs, &rhs.limbs)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_mag(&self.limbs, &rhs.limbs),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+/// The operators on owned numbers, in terms of the ones on references.
+macro_rules! owned_ops {
+    ($(($trait:ident, $method:ident)),*) => {
+        $(
+            impl $trait for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    $trait::$method(&self, &rhs)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: &BigInt) -> BigInt {
+                    $trait::$method(&self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+owned_ops!((Add, add), (Sub, sub), (Mul, mul), (Div, div), (Rem, rem));
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // peel off nine decimal digits at a time, least significant first
+        let mut chunks = vec![];
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (quotient, chunk) = divrem_limb(&rest, DECIMAL_BASE);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut digits = String::new();
+        match chunks.split_last() {
+            None => digits.push('0'),
+            Some((first, rest)) => {
+                digits.push_str(&first.to_string());
+                for chunk in rest.iter().rev() {
+                    digits.push_str(&format!("{:0width$}", chunk, width = DECIMAL_DIGITS));
+                }
+            }
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BigInt, String> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("'{}' is not a whole number", s));
+        }
+        // nine decimal digits at a time, most significant first, lining the chunks up from the
+        // right
+        let mut limbs: Vec<u32> = vec![];
+        let first = match digits.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            n => n,
+        };
+        let mut start = 0;
+        let mut end = first;
+        while start < digits.len() {
+            let chunk: u32 = digits[start..end].parse().unwrap();
+            let scale = 10u32.pow((end - start) as u32);
+            let mut carry = chunk as u64;
+            for limb in limbs.iter_mut() {
+                let total = *limb as u64 * scale as u64 + carry;
+                *limb = total as u32;
+                carry = total >> 32;
+            }
+            if carry > 0 {
+                limbs.push(carry as u32);
+            }
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(BigInt::from_parts(negative, limbs))
+    }
+}
+
+/// Whole numbers a day can do its sums in: machine integers, which overflow as [`checked`] says,
+/// or [`BigInt`]s, which never do.
+pub trait Integer: Clone + Ord + fmt::Display {
+    fn from_i64(value: i64) -> Self;
+    fn add(&self, rhs: &Self) -> Self;
+    fn sub(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+    /// Truncating towards zero.
+    fn div(&self, rhs: &Self) -> Self;
+    /// With the sign of `self`.
+    fn rem(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn from_i64(value: i64) -> $t {
+                    checked::cast(value)
+                }
+                fn add(&self, rhs: &$t) -> $t {
+                    checked::add(*self, *rhs)
+                }
+                fn sub(&self, rhs: &$t) -> $t {
+                    checked::sub(*self, *rhs)
+                }
+                fn mul(&self, rhs: &$t) -> $t {
+                    checked::mul(*self, *rhs)
+                }
+                fn div(&self, rhs: &$t) -> $t {
+                    checked::div(*self, *rhs)
+                }
+                fn rem(&self, rhs: &$t) -> $t {
+                    *self % *rhs
+                }
+            }
+        )*
+    };
+}
+
+integer!(usize, isize, u64, i64);
+
+impl Integer for BigInt {
+    fn from_i64(value: i64) -> BigInt {
+        BigInt::from(value)
+    }
+    fn add(&self, rhs: &BigInt) -> BigInt {
+        self + rhs
+    }
+    fn sub(&self, rhs: &BigInt) -> BigInt {
+        self - rhs
+    }
+    fn mul(&self, rhs: &BigInt) -> BigInt {
+        self * rhs
+    }
+    fn div(&self, rhs: &BigInt) -> BigInt {
+        self / rhs
+    }
+    fn rem(&self, rhs: &BigInt) -> BigInt {
+        self % rhs
+    }
+}
+
+/// Marks the setting as not yet read from the environment.
+const UNSET: u8 = u8::MAX;
+
+static EXACT: AtomicU8 = AtomicU8::new(UNSET);
+
+/// Whether `AOC_EXACT` asks for exact arithmetic.
+pub fn env_exact() -> bool {
+    env::var(EXACT_VAR).is_ok_and(|value| !matches!(value.trim(), "" | "0"))
+}
+
+/// Whether days should do their sums exactly, read from `AOC_EXACT` the first time if it hasn't
+/// been set.
+pub fn exact() -> bool {
+    match EXACT.load(AtomicOrdering::Relaxed) {
+        UNSET => {
+            let exact = env_exact();
+            set_exact(exact);
+            exact
+        }
+        n => n == 1,
+    }
+}
+
+pub fn set_exact(exact: bool) {
+    EXACT.store(exact as u8, AtomicOrdering::Relaxed);
+}
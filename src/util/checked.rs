@@ -0,0 +1,107 @@
+//! Arithmetic for the sums that could overflow on a big enough input.
+//!
+//! Release builds wrap around on overflow, which turns a too-big input into a wrong answer with
+//! no warning. With the `checked` feature, these functions check every operation instead and
+//! panic with the operation that overflowed, which the runner reports as the stage panicking.
+//! Without it, they are the plain operators and cost nothing.
+
+use std::{
+    any,
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+};
+
+/// The integer types the days do their sums in.
+pub trait Int:
+    Copy + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                fn checked_add(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_mul(self, rhs)
+                }
+                fn checked_div(self, rhs: $t) -> Option<$t> {
+                    <$t>::checked_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+int!(usize, isize, u64, i64);
+
+/// Whether the `checked` feature is on.
+pub const ENABLED: bool = cfg!(feature = "checked");
+
+/// `checked` with the feature on, naming the operation if it overflows, otherwise `plain`.
+#[track_caller]
+fn apply<T: Int>(
+    lhs: T,
+    op: &str,
+    rhs: T,
+    checked: fn(T, T) -> Option<T>,
+    plain: fn(T, T) -> T,
+) -> T {
+    if !ENABLED {
+        return plain(lhs, rhs);
+    }
+    match checked(lhs, rhs) {
+        Some(value) => value,
+        None => panic!(
+            "overflow: {} {} {} doesn't fit in {}",
+            lhs,
+            op,
+            rhs,
+            any::type_name::<T>()
+        ),
+    }
+}
+
+#[track_caller]
+pub fn add<T: Int>(lhs: T, rhs: T) -> T {
+    apply(lhs, "+", rhs, T::checked_add, <T as Add>::add)
+}
+
+#[track_caller]
+pub fn sub<T: Int>(lhs: T, rhs: T) -> T {
+    apply(lhs, "-", rhs, T::checked_sub, <T as Sub>::sub)
+}
+
+#[track_caller]
+pub fn mul<T: Int>(lhs: T, rhs: T) -> T {
+    apply(lhs, "*", rhs, T::checked_mul, <T as Mul>::mul)
+}
+
+/// Division, which also panics on dividing by zero with the feature on, as it always does
+/// without.
+#[track_caller]
+pub fn div<T: Int>(lhs: T, rhs: T) -> T {
+    apply(lhs, "/", rhs, T::checked_div, <T as Div>::div)
+}
+
+/// `value` as another integer type. Checked whether or not the feature is on, since no day does
+/// this in a hot loop.
+#[track_caller]
+pub fn cast<T: Int, U: TryFrom<T>>(value: T) -> U {
+    U::try_from(value).unwrap_or_else(|_| {
+        panic!(
+            "overflow: {} doesn't fit in {}",
+            value,
+            any::type_name::<U>()
+        )
+    })
+}
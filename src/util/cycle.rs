@@ -0,0 +1,78 @@
+//! Finding where a simulation starts repeating itself, so that it doesn't need running out to
+//! a trillion steps.
+//!
+//! The simulation is stepped forward until a summary of its state, the key, is one that has been
+//! seen before. Every step after that is a repeat of one inside the cycle, and any quantity that
+//! grows by the same amount each time round the cycle (like the height of the tower on day 17)
+//! can be extrapolated to as many steps as needed.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+use crate::util::geometry::Coord;
+
+/// The steps from `start` up to `start + length` repeat forever after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before the first repeat that is in the same state as `step`.
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// Extrapolate `values`, where `values[n]` is some quantity after `n` steps and must be
+    /// known up to the end of the first cycle, out to `target` steps. The quantity must change
+    /// by the same amount each time round the cycle.
+    pub fn extrapolate<T: Coord + TryFrom<usize>>(&self, values: &[T], target: usize) -> T {
+        if let Some(&value) = values.get(target) {
+            return value;
+        }
+        let end = self.start + self.length;
+        let per_cycle = values[end] - values[self.start];
+        let cycles = (target - self.start) / self.length;
+        let cycles = T::try_from(cycles)
+            .ok()
+            .expect("number of cycles should fit in the extrapolated quantity");
+        values[self.equivalent(target)] + per_cycle * cycles
+    }
+}
+
+/// Step `state` forward with `step` until the `key` of a state (including the initial one) is
+/// one seen before, giving up after `limit` steps. On success `state` is left as it was at the
+/// end of the first cycle.
+pub fn find_cycle<S, K: Eq + Hash>(
+    state: &mut S,
+    limit: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Option<Cycle> {
+    // the step each key was first seen at
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    seen.insert(key(state), 0);
+    for n in 1..=limit {
+        step(state);
+        match seen.entry(key(state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Some(Cycle {
+                    start,
+                    length: n - start,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(n);
+            }
+        }
+    }
+    None
+}
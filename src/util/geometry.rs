@@ -0,0 +1,239 @@
+//! Points on a plane or in space, the distances between them, and the headings to step along.
+//!
+//! Points on a plane follow the grid: `x` counts columns to the right and `y` counts rows
+//! *down*, so [`Heading::Up`] takes one off `y`. A `Point2<usize>` converts to and from a grid
+//! [`Pos`], which is the other way round, `(row, col)`.
+
+use std::{
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::util::grid::Pos;
+
+/// A number that can be used as a coordinate.
+pub trait Coord:
+    Copy + Ord + Hash + Default + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_coord!(i32, i64, isize, u32, u64, usize);
+
+/// `|a - b|`, without going below zero for unsigned coordinates.
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    /// Distance moving only along the axes.
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Distance where a diagonal step counts the same as a straight one, like a king in chess.
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Straight line distance.
+    pub fn euclidean(self, other: Point2<T>) -> f64 {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        (dx * dx + dy * dy).to_f64().sqrt()
+    }
+
+    /// The same point with another type of coordinate, if both coordinates fit in it.
+    pub fn cast<U: Coord + TryFrom<T>>(self) -> Option<Point2<U>> {
+        Some(Point2::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    /// Distance moving only along the axes.
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// Distance where a diagonal step counts the same as a straight one.
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    /// Straight line distance.
+    pub fn euclidean(self, other: Point3<T>) -> f64 {
+        let (dx, dy, dz) = (
+            abs_diff(self.x, other.x),
+            abs_diff(self.y, other.y),
+            abs_diff(self.z, other.z),
+        );
+        (dx * dx + dy * dy + dz * dz).to_f64().sqrt()
+    }
+
+    /// The same point with another type of coordinate, if every coordinate fits in it.
+    pub fn cast<U: Coord + TryFrom<T>>(self) -> Option<Point3<U>> {
+        Some(Point3::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+}
+
+impl Point2<usize> {
+    /// The grid position of this point, `(row, col)`.
+    pub fn pos(self) -> Pos {
+        (self.y, self.x)
+    }
+}
+
+impl From<Pos> for Point2<usize> {
+    fn from((row, col): Pos) -> Point2<usize> {
+        Point2::new(col, row)
+    }
+}
+
+/// Component-wise arithmetic, and scaling by a single coordinate.
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, scale: T) -> $point<T> {
+                $point { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl<T: Coord + Neg<Output = T>> Neg for $point<T> {
+            type Output = $point<T>;
+
+            fn neg(self) -> $point<T> {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: $point<T>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: $point<T>) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+/// One of the four directions along the axes of a plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Heading {
+    /// Every heading, clockwise from up.
+    pub const ALL: [Heading; 4] = [Heading::Up, Heading::Right, Heading::Down, Heading::Left];
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Left,
+            Heading::Right => Heading::Up,
+            Heading::Down => Heading::Right,
+            Heading::Left => Heading::Down,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Right,
+            Heading::Right => Heading::Down,
+            Heading::Down => Heading::Left,
+            Heading::Left => Heading::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Heading {
+        match self {
+            Heading::Up => Heading::Down,
+            Heading::Right => Heading::Left,
+            Heading::Down => Heading::Up,
+            Heading::Left => Heading::Right,
+        }
+    }
+
+    /// The step one square along this heading. Up is towards smaller `y`, like the rows of a
+    /// grid.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Heading::Up => Point2::new(0, -1),
+            Heading::Right => Point2::new(1, 0),
+            Heading::Down => Point2::new(0, 1),
+            Heading::Left => Point2::new(-1, 0),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! A rectangular grid of cells, for the many days whose input is a character map.
+//!
+//! Cells are stored row by row and addressed by `(row, col)`, with row 0 at the top, which is
+//! the order the input is read in.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut, Range},
+};
+
+use crate::error::{char_at, AocError};
+
+/// Position of a cell, `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// Steps to the four orthogonal neighbours, in reading order: up, left, right, down.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Steps to all eight neighbours, in reading order.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `height` by `width` grid with every cell set to `fill`.
+    pub fn new(height: usize, width: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            height,
+            width,
+            cells: vec![fill; height * width],
+        }
+    }
+
+    /// Read a character map, one row per line, turning each character into a cell with `f`.
+    /// Every row must be as wide as the first. `lines` must be slices of `input`, so that a bad
+    /// character can be reported where it was found; `expected` describes the characters `f`
+    /// accepts.
+    pub fn parse(
+        input: &str,
+        lines: &[&str],
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError>
+    where
+        T: Clone,
+    {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if let Some(line) = lines.iter().find(|line| line.chars().count() != width) {
+            return Err(AocError::at(input, line, format!("a row {} wide", width)));
+        }
+        Grid::read(input, lines, width, None, expected, f)
+    }
+
+    /// As [`Grid::parse`], but rows may be shorter than the widest one (like the indented map on
+    /// day 22), and are padded out with `pad`.
+    pub fn parse_ragged(
+        input: &str,
+        lines: &[&str],
+        pad: T,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError>
+    where
+        T: Clone,
+    {
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        Grid::read(input, lines, width, Some(pad), expected, f)
+    }
+
+    fn read(
+        input: &str,
+        lines: &[&str],
+        width: usize,
+        pad: Option<T>,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(lines.len() * width);
+        for line in lines {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(AocError::at(input, char_at(line, i), expected)),
+                }
+            }
+            if let Some(pad) = &pad {
+                cells.resize(row_start + width, pad.clone());
+            }
+        }
+        Ok(Grid {
+            height: lines.len(),
+            width,
+            cells,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// The cell at `pos`, or `None` if it's off the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The position one `step` of `(rows, cols)` away from `pos`, if that's still on the grid.
+    pub fn step(&self, (row, col): Pos, (drow, dcol): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(drow)?, col.checked_add_signed(dcol)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions on the grid reached by repeatedly taking `step` from `pos`, not including
+    /// `pos` itself. A step of `(0, 1)` walks the rest of a row, `(1, 0)` the rest of a column
+    /// and `(1, 1)` a diagonal.
+    pub fn ray(&self, pos: Pos, step: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, step), move |&pos| self.step(pos, step))
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&step| self.step(pos, step))
+    }
+
+    /// The up to eight neighbours of `pos`, diagonals included, that are on the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |&step| self.step(pos, step))
+    }
+
+    /// Every position on the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero width, even for an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        // step_by panics on a zero width, and a column past the edge has no cells at all
+        let rows = if col < self.width { self.height } else { 0 };
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(self.width.max(1))
+            .take(rows)
+    }
+
+    /// The cells on the diagonal through `pos` running down and to the right, from the edge of
+    /// the grid it starts on. `pos` must be on the grid.
+    pub fn diagonal(&self, (row, col): Pos) -> impl Iterator<Item = &T> {
+        let back = row.min(col);
+        self.line_from((row - back, col - back), (1, 1))
+    }
+
+    /// The cells on the diagonal through `pos` running down and to the left, from the edge of
+    /// the grid it starts on. `pos` must be on the grid.
+    pub fn anti_diagonal(&self, (row, col): Pos) -> impl Iterator<Item = &T> {
+        let back = row.min(self.width.saturating_sub(col + 1));
+        self.line_from((row - back, col + back), (1, -1))
+    }
+
+    /// The cell at `start` and those reached from it by taking `step` until the edge.
+    fn line_from(&self, start: Pos, step: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::once(start)
+            .chain(self.ray(start, step))
+            .map(|pos| &self[pos])
+    }
+
+    /// A new grid with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Build a `height` by `width` grid whose cell at `(row, col)` is `self[from((row, col))]`.
+    fn remap(&self, height: usize, width: usize, from: impl Fn(Pos) -> Pos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|pos| self[from(pos)].clone())
+            .collect();
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Swap rows and columns, so that `out[(i, j)] == self[(j, i)]`.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |(row, col)| (col, row))
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |(row, col)| {
+            (height - 1 - col, row)
+        })
+    }
+
+    /// Rotate a quarter turn anticlockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |(row, col)| (col, width - 1 - row))
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |(row, col)| (row, width - 1 - col))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |(row, col)| {
+            (height - 1 - row, col)
+        })
+    }
+
+    /// Insert `count` rows of `fill` before row `at`, moving the rows below down.
+    pub fn insert_rows(&mut self, at: usize, count: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let start = at * self.width;
+        self.cells
+            .splice(start..start, std::iter::repeat_n(fill, count * self.width));
+        self.height += count;
+    }
+
+    /// Remove the rows in `rows`, moving the rows below up.
+    pub fn remove_rows(&mut self, rows: Range<usize>) {
+        self.cells
+            .drain(rows.start * self.width..rows.end * self.width);
+        self.height -= rows.len();
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a grid of {} rows by {} columns",
+                pos, self.height, self.width
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside a grid of {} rows by {} columns",
+                pos, height, width
+            ),
+        }
+    }
+}
+
+/// Draws the grid one row per line, with no trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
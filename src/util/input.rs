@@ -0,0 +1,117 @@
+//! Locating and reading puzzle inputs.
+//!
+//! By default the input for day N is `src/data/dayN_1.txt` inside this crate, wherever it is
+//! checked out and whatever the current directory is. Setting `AOC_INPUT_DIR` looks for
+//! `dayN_1.txt` in that directory instead, and the runner can override both with an explicit
+//! [`Source`].
+//!
+//! Whichever way the text arrives, days should split it with [`lines`], [`records`] or [`trim`]
+//! rather than by hand, so that CRLF and LF files and a trailing newline all parse the same.
+
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// Environment variable naming a directory holding `dayN_1.txt` files.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read an input from instead of the default location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Interpret a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Source {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+}
+
+static OVERRIDE: OnceLock<Source> = OnceLock::new();
+static STDIN: OnceLock<String> = OnceLock::new();
+
+/// Read every day's input from `source` rather than the default location. Can only be set once;
+/// returns the rejected source if an override is already in place.
+pub fn set_override(source: Source) -> Result<(), Source> {
+    OVERRIDE.set(source)
+}
+
+/// Default location of the input for `day`, honouring `AOC_INPUT_DIR`.
+pub fn default_path(day: usize) -> PathBuf {
+    let dir = match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join("data"),
+    };
+    dir.join(format!("day{}_1.txt", day))
+}
+
+/// Human readable description of where the input for `day` will be read from.
+pub fn describe(day: usize) -> String {
+    match OVERRIDE.get() {
+        Some(Source::Stdin) => String::from("<stdin>"),
+        Some(Source::File(path)) => path.display().to_string(),
+        None => default_path(day).display().to_string(),
+    }
+}
+
+/// Read the whole input for `day`. Stdin is only consumed once, so both parts of a day see the
+/// same text.
+pub fn read(day: usize) -> io::Result<String> {
+    match OVERRIDE.get() {
+        Some(Source::Stdin) => read_stdin(),
+        Some(Source::File(path)) => read_file(path),
+        None => read_file(&default_path(day)),
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+}
+
+fn read_stdin() -> io::Result<String> {
+    if let Some(text) = STDIN.get() {
+        return Ok(text.clone());
+    }
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+    Ok(STDIN.get_or_init(|| text).clone())
+}
+
+/// The text with any leading or trailing line breaks removed. Spaces are kept, since some inputs
+/// (the crate drawing on day 5, the map on day 22) are indented.
+pub fn trim(text: &str) -> &str {
+    text.trim_matches(|c| c == '\n' || c == '\r')
+}
+
+/// Every line of the text, without line endings. Either `\n` or `\r\n` ends a line, and a
+/// trailing newline does not produce an empty last line.
+pub fn lines(text: &str) -> Vec<&str> {
+    trim(text).lines().collect()
+}
+
+/// Groups of consecutive lines separated by blank lines.
+pub fn records(text: &str) -> Vec<Vec<&str>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    for line in lines(text) {
+        if line.is_empty() {
+            records.push(record);
+            record = vec![];
+        } else {
+            record.push(line);
+        }
+    }
+    records.push(record);
+    records
+}
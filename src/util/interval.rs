@@ -0,0 +1,154 @@
+//! Sets of integers stored as the half-open ranges they cover, for days that reason about
+//! stretches of a line (section assignments on day 4, the columns a sensor covers on day 15)
+//! instead of individual points.
+
+use std::ops::Range;
+
+use crate::util::geometry::Coord;
+
+/// A set of `T`, kept as sorted, disjoint ranges. Ranges that overlap or merely touch (like
+/// `0..3` and `3..5`) are merged as they are inserted, so each range is followed by a real gap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::default(), |total, range| {
+            total + (range.end - range.start)
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// Add every value in `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // the ranges from `first` up to `last` overlap or touch the new one, and are replaced
+        // by a single range covering them all
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Take every value in `range` out of the set.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+        // only the ends of the outermost overlapping ranges survive
+        let mut left_over = vec![];
+        if self.ranges[first].start < range.start {
+            left_over.push(self.ranges[first].start..range.start);
+        }
+        if self.ranges[last - 1].end > range.end {
+            left_over.push(range.end..self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, left_over);
+    }
+
+    /// Values in either set.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    /// Values in both sets.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intersection = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                intersection.ranges.push(overlap);
+            }
+            // move on from whichever range finishes first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        intersection
+    }
+
+    /// Values in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: Range<T>) -> IntervalSet<T> {
+        let mut complement = IntervalSet::from(bounds);
+        for range in &self.ranges {
+            complement.remove(range.clone());
+        }
+        complement
+    }
+
+    /// The gaps between the ranges of the set, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| pair[0].end..pair[1].start)
+    }
+}
+
+impl<T: Coord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Coord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+/// Collects faster than inserting one at a time, by sorting the ranges and merging them in a
+/// single pass.
+impl<T: Coord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> IntervalSet<T> {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_by_key(|r| r.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        IntervalSet { ranges: merged }
+    }
+}
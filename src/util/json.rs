@@ -0,0 +1,33 @@
+//! Just enough JSON to write reports for other tools to read, without pulling in a serialiser.
+
+use std::fmt::Write;
+
+/// `s` as a JSON string, quoted and escaped.
+pub fn string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// `value` as a JSON string, or `null` if there isn't one.
+pub fn optional(value: Option<&str>) -> String {
+    value.map_or(String::from("null"), string)
+}
+
+/// Each of `items` as a JSON string, in an array.
+pub fn strings<S: AsRef<str>>(items: &[S]) -> String {
+    let items: Vec<String> = items.iter().map(|item| string(item.as_ref())).collect();
+    format!("[{}]", items.join(", "))
+}
@@ -0,0 +1,62 @@
+//! Reading the capital letters some puzzles draw instead of giving a number, like day 10's CRT.
+//!
+//! Every puzzle uses the same font: letters four pixels wide and six tall, lit with `#`, with a
+//! column of space after each. Only the letters that have turned up in puzzles are known.
+
+/// Each known letter, as its six rows.
+const FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The pixels a letter takes up, including the space after it.
+const WIDTH: usize = 5;
+
+/// The letters drawn in `rows`, or `None` if they aren't all letters in the font. Any character
+/// other than `#` is taken to be unlit.
+pub fn read<S: AsRef<str>>(rows: &[S]) -> Option<String> {
+    let rows: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c == '#').collect())
+        .collect();
+    let width = rows.first()?.len();
+    if width == 0 || rows.len() != 6 || rows.iter().any(|row| row.len() != width) {
+        return None;
+    }
+    // the space after the last letter is often left off
+    let count = width.div_ceil(WIDTH);
+    (0..count)
+        .map(|i| {
+            let lit = |row: &[bool], x: usize| row.get(i * WIDTH + x).copied().unwrap_or(false);
+            if rows.iter().any(|row| lit(row, WIDTH - 1)) {
+                return None;
+            }
+            FONT.iter()
+                .find(|(_, glyph)| {
+                    glyph.iter().zip(&rows).all(|(glyph_row, row)| {
+                        glyph_row
+                            .chars()
+                            .enumerate()
+                            .all(|(x, c)| (c == '#') == lit(row, x))
+                    })
+                })
+                .map(|&(letter, _)| letter)
+        })
+        .collect()
+}
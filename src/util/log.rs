@@ -0,0 +1,189 @@
+//! Leveled diagnostics, written to stderr so that stdout only ever holds answers.
+//!
+//! Days report what they are doing with the [`error!`](crate::error!), [`info!`](crate::info!),
+//! [`debug!`](crate::debug!) and [`trace!`](crate::trace!) macros. Only messages at or below the
+//! current level are written, and the arguments of the rest are never even formatted, so tracing
+//! every step of a hot loop costs next to nothing when it is turned off.
+//!
+//! The level starts out as `info`, or whatever `AOC_LOG` names, and the runner's `-v` and `-q`
+//! flags move it up and down from there.
+
+use std::{
+    env, fmt,
+    io::{self, Write},
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::error;
+
+/// Environment variable naming the starting level, one of `error`, `info`, `debug` or `trace`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// How much to report, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Something went wrong.
+    Error,
+    /// What the runner is doing.
+    Info,
+    /// A line or two from each day about how it got its answer.
+    Debug,
+    /// Every step, and pictures of the state along the way. Very noisy on real inputs.
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 4] = [Level::Error, Level::Info, Level::Debug, Level::Trace];
+
+    /// The next level up, or this one if there is none.
+    pub fn louder(self) -> Level {
+        Level::ALL[(self as usize + 1).min(Level::ALL.len() - 1)]
+    }
+
+    /// The next level down, or this one if there is none.
+    pub fn quieter(self) -> Level {
+        Level::ALL[(self as usize).saturating_sub(1)]
+    }
+
+    /// The level after a command line flag moves it: `--verbose` and `--quiet` by one level, or
+    /// any run of `v`s and `q`s, like `-vv`, by one level each in turn. `None` if `flag` isn't
+    /// one of these.
+    pub fn after_flag(self, flag: &str) -> Option<Level> {
+        match flag {
+            "--verbose" => Some(self.louder()),
+            "--quiet" => Some(self.quieter()),
+            _ => {
+                let run = flag.strip_prefix('-').filter(|run| !run.is_empty())?;
+                run.chars().try_fold(self, |level, c| match c {
+                    'v' => Some(level.louder()),
+                    'q' => Some(level.quieter()),
+                    _ => None,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                format!(
+                    "'{}' is not a log level, expected error, info, debug or trace",
+                    s
+                )
+            })
+    }
+}
+
+/// Marks the level as not yet read from the environment.
+const UNSET: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+/// The level named by `AOC_LOG`, or `info` if it is unset. An unrecognised name is an error,
+/// for the caller to report once it has settled on a level.
+pub fn env_level() -> Result<Level, String> {
+    match env::var(LOG_VAR) {
+        Ok(name) => name
+            .parse()
+            .map_err(|message| format!("{}: {}", LOG_VAR, message)),
+        Err(_) => Ok(Level::Info),
+    }
+}
+
+/// The current level, read from `AOC_LOG` the first time if it hasn't been set. An unrecognised
+/// name is reported and ignored.
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => match env_level() {
+            Ok(level) => {
+                set_level(level);
+                level
+            }
+            Err(message) => {
+                set_level(Level::Info);
+                error!("{}", message);
+                Level::Info
+            }
+        },
+        n => Level::ALL[n as usize],
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` are written, for when working out what to say is expensive.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Write a message to stderr, with every line tagged with its level. Use the macros instead,
+/// which check the level first.
+pub fn write(level: Level, args: fmt::Arguments<'_>) {
+    let message = args.to_string();
+    // hold the lock for the whole message, so that pictures from different threads don't mix
+    let mut stderr = io::stderr().lock();
+    for line in message.lines() {
+        // there's nowhere left to report a failure to write to stderr
+        let _ = writeln!(stderr, "[{}] {}", level, line);
+    }
+}
+
+/// Log at a given [`Level`](crate::util::log::Level).
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {{
+        let level = $level;
+        if $crate::util::log::enabled(level) {
+            $crate::util::log::write(level, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::util::log::Level::Error, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::util::log::Level::Info, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::util::log::Level::Debug, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::util::log::Level::Trace, $($arg)+)
+    };
+}
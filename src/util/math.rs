@@ -0,0 +1,20 @@
+//! Number theory the days need now and then, like the common period of things repeating at
+//! different rates.
+
+use crate::util::checked;
+
+/// Greatest common divisor, which is `a` if `b` is zero.
+pub fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, the period of something made of two parts that repeat every `a` and
+/// `b` steps.
+#[track_caller]
+pub fn lcm(a: usize, b: usize) -> usize {
+    checked::mul(a / gcd(a, b), b)
+}
@@ -0,0 +1,16 @@
+//! Helpers shared between days.
+
+pub mod bigint;
+pub mod checked;
+pub mod cycle;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod letters;
+pub mod log;
+pub mod math;
+pub mod par;
+pub mod render;
+pub mod search;
@@ -0,0 +1,138 @@
+//! Spreading independent pieces of work across threads, for days that have plenty of them.
+//!
+//! The work is split by index: each thread claims the next batch of indices as it finishes its
+//! last, so a few slow pieces (like the harder blueprints on day 19) don't hold up the rest.
+//! Results come back in index order, whichever thread found them and whenever, so answers are
+//! the same however many threads there are.
+//!
+//! The thread count is the number of CPUs, or whatever `AOC_THREADS` says, and the runner's
+//! `--threads` flag overrides both. One thread runs everything in order on the calling thread.
+
+use std::{
+    env,
+    ops::Range,
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use crate::error;
+
+/// Environment variable giving the number of threads to use.
+pub const THREADS_VAR: &str = "AOC_THREADS";
+
+/// Batches of work made for each thread, so that they finish at about the same time.
+const BATCHES_PER_THREAD: usize = 16;
+
+/// Marks the thread count as not yet read from the environment.
+const UNSET: usize = 0;
+
+static THREADS: AtomicUsize = AtomicUsize::new(UNSET);
+
+/// The thread count named by `AOC_THREADS`, or the number of CPUs if it is unset. An
+/// unrecognised count is reported and ignored.
+pub fn env_threads() -> usize {
+    let cpus = || thread::available_parallelism().map_or(1, |n| n.get());
+    match env::var(THREADS_VAR) {
+        Ok(value) => match value.trim().parse() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                error!("{}: '{}' is not a number of threads", THREADS_VAR, value);
+                cpus()
+            }
+        },
+        Err(_) => cpus(),
+    }
+}
+
+/// The current thread count, read from `AOC_THREADS` the first time if it hasn't been set.
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        UNSET => {
+            let threads = env_threads();
+            set_threads(threads);
+            threads
+        }
+        n => n,
+    }
+}
+
+/// Use `threads` threads from now on, or one if that is zero.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+/// `f` of every item, in order.
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let mut results = run(items.len(), false, |i| Some(f(&items[i])));
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// `f` of the first index in `range` it gives a result for, as if tried in order. Threads stop
+/// claiming work once there is a result, and indices after it might never be tried.
+pub fn par_find_first<R: Send>(
+    range: Range<usize>,
+    f: impl Fn(usize) -> Option<R> + Sync,
+) -> Option<R> {
+    let start = range.start;
+    run(range.len(), true, |i| f(start + i))
+        .into_iter()
+        .min_by_key(|&(i, _)| i)
+        .map(|(_, result)| result)
+}
+
+/// Call `f` on indices in `0..len` across the threads, returning each result with its index, in
+/// no particular order. With `first_only`, only indices that could come before the first result
+/// are tried, and that result is among those returned.
+fn run<R: Send>(
+    len: usize,
+    first_only: bool,
+    f: impl Fn(usize) -> Option<R> + Sync,
+) -> Vec<(usize, R)> {
+    let threads = threads().min(len);
+    if threads <= 1 {
+        let results = (0..len).filter_map(|i| f(i).map(|result| (i, result)));
+        return if first_only {
+            results.take(1).collect()
+        } else {
+            results.collect()
+        };
+    }
+
+    let batch = (len / (threads * BATCHES_PER_THREAD)).max(1);
+    let next = AtomicUsize::new(0);
+    // the lowest index with a result, when only the first is wanted
+    let first = AtomicUsize::new(usize::MAX);
+    let worker = || {
+        let mut results = vec![];
+        loop {
+            let start = next.fetch_add(batch, Ordering::Relaxed);
+            // a batch that starts after a result can't hold the first one
+            if start >= len || start > first.load(Ordering::Relaxed) {
+                return results;
+            }
+            for i in start..(start + batch).min(len) {
+                if let Some(result) = f(i) {
+                    results.push((i, result));
+                    if first_only {
+                        first.fetch_min(i, Ordering::Relaxed);
+                        break;
+                    }
+                }
+            }
+        }
+    };
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads).map(|_| scope.spawn(worker)).collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                // pass on a panic in `f` as if it happened here
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
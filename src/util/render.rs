@@ -0,0 +1,259 @@
+//! Drawing the state of a puzzle, as plain text, coloured text for a terminal, or an image.
+//!
+//! A [`Picture`] is a grid of cells, each with a character for the text formats and a colour for
+//! everything else. Days build one from their own state with a function that styles each cell,
+//! and whoever wants to look at it picks a [`Format`]: text to compare in tests, ANSI for a
+//! terminal, or PPM and SVG to keep or embed in a report.
+
+use std::{fmt, fmt::Write, str::FromStr};
+
+use crate::util::{
+    geometry::Point2,
+    grid::{Grid, Pos},
+};
+
+/// A colour, as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const DARK: Rgb = Rgb(40, 40, 40);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(70, 130, 230);
+    pub const YELLOW: Rgb = Rgb(240, 200, 60);
+    pub const BROWN: Rgb = Rgb(140, 90, 50);
+    pub const SAND: Rgb = Rgb(230, 190, 120);
+    pub const ICE: Rgb = Rgb(180, 220, 250);
+
+    /// As used by HTML and SVG, like `#ff8000`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// How to draw a single cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Style {
+    /// Drawn by the text formats.
+    pub glyph: char,
+    /// Colours the glyph in a terminal, and fills the cell in an image.
+    pub colour: Rgb,
+}
+
+impl Style {
+    pub const fn new(glyph: char, colour: Rgb) -> Style {
+        Style { glyph, colour }
+    }
+}
+
+/// A rectangle of styled cells, ready to be written out in any [`Format`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    cells: Grid<Style>,
+}
+
+impl Picture {
+    /// Style every cell of `grid`.
+    pub fn from_grid<T>(grid: &Grid<T>, style: impl FnMut(&T) -> Style) -> Picture {
+        Picture {
+            cells: grid.map(style),
+        }
+    }
+
+    /// A `height` by `width` picture, styling each position in turn.
+    pub fn from_fn(height: usize, width: usize, mut style: impl FnMut(Pos) -> Style) -> Picture {
+        let mut cells = Grid::new(height, width, Style::new(' ', Rgb::BLACK));
+        for pos in cells.positions() {
+            cells[pos] = style(pos);
+        }
+        Picture { cells }
+    }
+
+    /// Just big enough to hold every one of `points`, with `x` across and `y` down. Each point
+    /// is drawn with `style`, and everywhere else with `blank`.
+    pub fn from_points(
+        points: impl IntoIterator<Item = Point2>,
+        blank: Style,
+        mut style: impl FnMut(Point2) -> Style,
+    ) -> Picture {
+        let points: Vec<Point2> = points.into_iter().collect();
+        let (Some(min), Some(max)) = (
+            points
+                .iter()
+                .copied()
+                .reduce(|a, b| Point2::new(a.x.min(b.x), a.y.min(b.y))),
+            points
+                .iter()
+                .copied()
+                .reduce(|a, b| Point2::new(a.x.max(b.x), a.y.max(b.y))),
+        ) else {
+            return Picture {
+                cells: Grid::new(0, 0, blank),
+            };
+        };
+        let size = (max - min).cast::<usize>().unwrap();
+        let mut cells = Grid::new(size.y + 1, size.x + 1, blank);
+        for point in points {
+            cells[(point - min).cast::<usize>().unwrap().pos()] = style(point);
+        }
+        Picture { cells }
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn cells(&self) -> &Grid<Style> {
+        &self.cells
+    }
+
+    /// Draw over a single cell.
+    pub fn set(&mut self, pos: Pos, style: Style) {
+        self.cells[pos] = style;
+    }
+
+    /// The glyphs, one row per line, with no trailing newline. The same as `to_string`.
+    pub fn text(&self) -> String {
+        self.to_string()
+    }
+
+    /// The glyphs in colour, using the 24-bit escape codes most terminals understand. Every line
+    /// ends by resetting the colour.
+    pub fn ansi(&self) -> String {
+        let mut ansi = String::new();
+        for (i, row) in self.cells.rows().enumerate() {
+            if i > 0 {
+                ansi.push('\n');
+            }
+            let mut current = None;
+            for cell in row {
+                if current != Some(cell.colour) {
+                    let Rgb(r, g, b) = cell.colour;
+                    write!(ansi, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                    current = Some(cell.colour);
+                }
+                ansi.push(cell.glyph);
+            }
+            ansi.push_str("\x1b[0m");
+        }
+        ansi
+    }
+
+    /// A binary PPM image, with each cell drawn as a `scale` by `scale` square.
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let mut ppm = format!(
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )
+        .into_bytes();
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    let Rgb(r, g, b) = cell.colour;
+                    for _ in 0..scale {
+                        ppm.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+        ppm
+    }
+
+    /// An SVG image, with each cell drawn as a `scale` by `scale` square. Runs of the same
+    /// colour along a row are drawn as one rectangle, to keep the file small.
+    pub fn svg(&self, scale: usize) -> String {
+        let scale = scale.max(1);
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+             viewBox=\"0 0 {0} {1}\" shape-rendering=\"crispEdges\">",
+            width, height
+        )
+        .unwrap();
+        for (y, row) in self.cells.rows().enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a.colour == b.colour) {
+                writeln!(
+                    svg,
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x * scale,
+                    y * scale,
+                    run.len() * scale,
+                    scale,
+                    run[0].colour.hex()
+                )
+                .unwrap();
+                x += run.len();
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The picture written out in `format`, scaling the image formats by `scale`.
+    pub fn encode(&self, format: Format, scale: usize) -> Vec<u8> {
+        match format {
+            Format::Text => format!("{}\n", self.text()).into_bytes(),
+            Format::Ansi => format!("{}\n", self.ansi()).into_bytes(),
+            Format::Ppm => self.ppm(scale),
+            Format::Svg => self.svg(scale).into_bytes(),
+        }
+    }
+}
+
+/// Draws the glyphs, one row per line, with no trailing newline.
+impl fmt::Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells.map(|cell| cell.glyph))
+    }
+}
+
+/// Ways of writing out a [`Picture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Ansi,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    /// The format usually kept in files ending `.extension`, if there is one.
+    pub fn from_extension(extension: &str) -> Option<Format> {
+        match extension.to_ascii_lowercase().as_str() {
+            "txt" => Some(Format::Text),
+            "ppm" => Some(Format::Ppm),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "ansi" => Ok(Format::Ansi),
+            "ppm" => Ok(Format::Ppm),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!(
+                "'{}' is not a picture format, expected text, ansi, ppm or svg",
+                s
+            )),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Searches over graphs that are never built up front. The caller describes the graph with a
+//! closure giving the neighbours of a node (and, for the weighted searches, the cost of getting
+//! to each), and says when to stop with `is_goal`. Pass `|_| false` to explore everything
+//! reachable.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// What a search found: the distance to every node it reached from the nearest start, and the
+/// node it reached each one from, so that paths can be walked back.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, usize>,
+    previous: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal node the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Distance from the nearest start to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distance to every node reached.
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// The path from the nearest start to `node`, both included, if it was reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal, if one was found.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth first search from `start`, where every edge has length 1.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Breadth first search from all of `starts` at once, so that distances are to the nearest of
+/// them.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node];
+        for next in neighbours(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.previous.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Shortest paths from `start` where `neighbours` gives each neighbour along with the cost of
+/// the edge to it.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// As [`dijkstra`], but heading towards the goal first using `heuristic`, an estimate of the
+/// distance left from a node to the goal. The estimate must never be more than the real
+/// distance, nor drop by more than the cost of an edge along it, or the path found may not be
+/// the shortest.
+///
+/// Distances are only final for nodes the search finished with before reaching the goal.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new();
+    // The heap holds (estimated total distance, index into `nodes`), so that nodes don't need
+    // to be ordered themselves; ties go to whichever node was queued first.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut nodes = vec![start.clone()];
+    let mut done: HashSet<N> = HashSet::new();
+    search.distances.insert(start, 0);
+    while let Some(Reverse((_, i))) = heap.pop() {
+        let node = nodes[i].clone();
+        if !done.insert(node.clone()) {
+            // already reached by a shorter route
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let distance = search.distances[&node];
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&known| next_distance < known)
+            {
+                search.distances.insert(next.clone(), next_distance);
+                search.previous.insert(next.clone(), node.clone());
+                heap.push(Reverse((next_distance + heuristic(&next), nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
@@ -0,0 +1,27 @@
+//! Every day against our own puzzle input, from `src/data`, so that refactoring a solver can't
+//! quietly change its answer.
+
+mod common;
+
+fn check_answer(day: usize) {
+    let answers = common::read_answers("src/data/answers.txt");
+    let input = common::read_input(&format!("src/data/day{}_1.txt", day));
+    common::check(day, &input, &answers);
+}
+
+macro_rules! answers {
+    ($($name:ident: $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_answer($day);
+            }
+        )*
+    };
+}
+
+answers!(
+    day1: 1, day2: 2, day3: 3, day4: 4, day5: 5, day6: 6, day7: 7, day8: 8, day9: 9,
+    day10: 10, day11: 11, day12: 12, day13: 13, day14: 14, day15: 15, day16: 16, day17: 17,
+    day18: 18, day19: 19, day20: 20, day21: 21, day22: 22, day23: 23, day24: 24, day25: 25,
+);
@@ -0,0 +1,64 @@
+//! Summary statistics of benchmark runs, on fixed samples worked out by hand.
+
+use std::time::Duration;
+
+use aoc::bench::Stats;
+
+fn millis(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&ms| Duration::from_millis(ms))
+        .collect()
+}
+
+/// Whether `actual` is within a nanosecond of `expected`, which is all the rounding through
+/// floating point costs.
+fn close(actual: Duration, expected: f64) -> bool {
+    (actual.as_secs_f64() - expected).abs() < 1e-9
+}
+
+#[test]
+fn even_count() {
+    // unsorted, with a mean of 5 and squared deviations summing to 32
+    let stats = Stats::from_samples(&millis(&[9, 4, 2, 5, 4, 7, 4, 5]));
+    assert_eq!(stats.iterations, 8);
+    assert_eq!(stats.min, Duration::from_millis(2));
+    // halfway between the middle two
+    assert_eq!(stats.median, Duration::from_micros(4500));
+    assert!(close(stats.mean, 0.005));
+    assert!(
+        close(stats.stddev, (32.0_f64 / 7.0).sqrt() / 1000.0),
+        "{:?}",
+        stats
+    );
+}
+
+#[test]
+fn odd_count() {
+    let stats = Stats::from_samples(&millis(&[3, 1, 2]));
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(2));
+    assert!(close(stats.mean, 0.002));
+    assert!(close(stats.stddev, 0.001), "{:?}", stats);
+}
+
+#[test]
+fn one_sample() {
+    let stats = Stats::from_samples(&millis(&[7]));
+    assert_eq!(stats.iterations, 1);
+    assert_eq!(
+        (stats.min, stats.median),
+        (Duration::from_millis(7), Duration::from_millis(7))
+    );
+    assert!(close(stats.mean, 0.007));
+    assert_eq!(stats.stddev, Duration::ZERO);
+
+    let same = Stats::from_samples(&millis(&[7, 7, 7]));
+    assert_eq!(same.stddev, Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "need at least one sample")]
+fn no_samples() {
+    Stats::from_samples(&[]);
+}
@@ -0,0 +1,177 @@
+//! Arbitrary-precision integers, checked against `i128` where the numbers fit and against
+//! identities where they don't, and the days that can use them.
+
+mod common;
+
+// the generator the oracles use, of which these tests only need some
+#[allow(dead_code)]
+#[path = "oracles/rng.rs"]
+mod rng;
+
+use std::cmp::Ordering;
+
+use aoc::{
+    solution::Part,
+    util::bigint::{self, BigInt},
+};
+use rng::Rng;
+
+fn big(s: &str) -> BigInt {
+    s.parse().unwrap()
+}
+
+/// A number of up to `limbs` 32-bit limbs, of either sign.
+fn random(rng: &mut Rng, limbs: usize) -> BigInt {
+    let mut n = BigInt::zero();
+    let base = BigInt::from(1u64 << 32);
+    for _ in 0..rng.range(0..=limbs as i64) {
+        n = &(&n * &base) + &BigInt::from(rng.next_u64() as u32);
+    }
+    if rng.one_in(2) {
+        -n
+    } else {
+        n
+    }
+}
+
+#[test]
+fn decimal() {
+    for s in [
+        "0",
+        "7",
+        "-7",
+        "4294967296",
+        "-18446744073709551616",
+        "1000000000",
+        "999999999999999999999999999",
+        "-100000000000000000000000000000000000000000000000000",
+    ] {
+        assert_eq!(big(s).to_string(), s);
+    }
+    assert_eq!(big("+12").to_string(), "12");
+    assert_eq!(big("-0").to_string(), "0");
+    assert_eq!(big("000123").to_string(), "123");
+    assert_eq!(format!("{:>6}", big("-12")), "   -12");
+    for s in ["", "-", "12a", "1 2", "0x10"] {
+        assert_eq!(
+            s.parse::<BigInt>(),
+            Err(format!("'{}' is not a whole number", s))
+        );
+    }
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(BigInt::from(i64::MIN).to_string(), i64::MIN.to_string());
+    assert_eq!(BigInt::from(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+    assert_eq!(BigInt::from(i128::MAX).to_i128(), Some(i128::MAX));
+    assert_eq!((BigInt::from(i128::MAX) + BigInt::from(1)).to_i128(), None);
+    assert_eq!(BigInt::from(-5).abs(), BigInt::from(5));
+    assert!(BigInt::from(-5).is_negative());
+    assert!(!(-BigInt::zero()).is_negative());
+}
+
+#[test]
+fn small_numbers_agree_with_i128() {
+    let mut rng = Rng::new(0);
+    for _ in 0..20000 {
+        let a = rng.next_u64() as i64 as i128;
+        let b = rng.next_u64() as i64 as i128 >> rng.below(64);
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!((&x + &y).to_i128(), Some(a + b), "{} + {}", a, b);
+        assert_eq!((&x - &y).to_i128(), Some(a - b), "{} - {}", a, b);
+        assert_eq!((&x * &y).to_i128(), Some(a * b), "{} * {}", a, b);
+        assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+        if b != 0 {
+            assert_eq!((&x / &y).to_i128(), Some(a / b), "{} / {}", a, b);
+            assert_eq!((&x % &y).to_i128(), Some(a % b), "{} % {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn big_numbers_divide_back() {
+    let mut rng = Rng::new(1);
+    for _ in 0..300 {
+        // long enough for Karatsuba, some of the time
+        let a = random(&mut rng, 80);
+        let b = random(&mut rng, 80);
+        if b.is_zero() {
+            continue;
+        }
+        let product = &a * &b;
+        assert_eq!(&product / &b, a);
+        assert!((&product % &b).is_zero());
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(&(&q * &b) + &r, a);
+        assert_eq!(r.abs().cmp(&b.abs()), Ordering::Less);
+        assert!(r.is_zero() || r.is_negative() == a.is_negative());
+        assert_eq!(&(&a + &b) - &b, a);
+        assert_eq!((&a - &b).cmp(&BigInt::zero()), a.cmp(&b), "{} - {}", a, b);
+    }
+}
+
+#[test]
+fn powers() {
+    assert_eq!(
+        BigInt::from(2).pow(200).to_string(),
+        "1606938044258990275541962092341162602522202993782792835301376"
+    );
+    assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+    assert_eq!(BigInt::from(7).pow(0), BigInt::from(1));
+    // squares long enough for Karatsuba, against the digits of 10^n
+    let ten = BigInt::from(10);
+    let power = ten.pow(1000);
+    let square = &power * &power;
+    assert_eq!(square.to_string(), format!("1{}", "0".repeat(2000)));
+    assert_eq!(&square / &power, power);
+    let nines = &power - &BigInt::from(1);
+    assert_eq!(
+        (&nines * &nines).to_string(),
+        format!("{}8{}1", "9".repeat(999), "0".repeat(999))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn dividing_by_zero() {
+    let _ = BigInt::from(1) / BigInt::zero();
+}
+
+/// The days that can do their sums exactly get the same answers that way.
+#[test]
+fn exact_days() {
+    bigint::set_exact(true);
+    let examples = common::read_answers("tests/examples/answers.txt");
+    let answers = common::read_answers("src/data/answers.txt");
+    for day in [11, 21, 25] {
+        let example = common::read_input(&format!("tests/examples/day{}.txt", day));
+        common::check(day, &example, &examples);
+        let input = common::read_input(&format!("src/data/day{}_1.txt", day));
+        common::check(day, &input, &answers);
+    }
+}
+
+/// Sums too big for machine integers come out right with exact arithmetic.
+#[test]
+fn exact_days_with_big_numbers() {
+    bigint::set_exact(true);
+    let solve = |day, input: &str| {
+        let parsed = aoc::days::get(day).unwrap().parse_input(input).unwrap();
+        [Part::One, Part::Two].map(|part| parsed.solve(part).map(|answer| answer.to_string()))
+    };
+    let monkeys = "root: abcd + humn\nabcd: efgh * efgh\nefgh: 4294967296\nhumn: 5\n";
+    assert_eq!(
+        solve(21, monkeys),
+        [
+            Some(String::from("18446744073709551621")),
+            Some(String::from("18446744073709551616"))
+        ]
+    );
+    let fuel = format!("{}\n{}\n", "2".repeat(30), "2".repeat(30));
+    assert_eq!(
+        solve(25, &fuel),
+        [Some(format!("1{}-", "0".repeat(29))), None]
+    );
+}
@@ -0,0 +1,72 @@
+//! The arithmetic behind the `checked` feature. The overflows are only caught with the feature on,
+//! so those tests need `cargo test --features checked`.
+
+use aoc::util::checked;
+
+#[test]
+fn sums_that_fit() {
+    assert_eq!(checked::add(2usize, 3), 5);
+    assert_eq!(checked::sub(2isize, 3), -1);
+    assert_eq!(checked::mul(811589153i64, -3), -2434767459);
+    assert_eq!(checked::div(-7i64, 2), -3);
+    assert_eq!(checked::cast::<i64, usize>(12), 12usize);
+}
+
+#[test]
+#[should_panic(expected = "overflow: -1 doesn't fit in usize")]
+fn casts() {
+    checked::cast::<i64, usize>(-1);
+}
+
+#[cfg(feature = "checked")]
+mod overflows {
+    use aoc::{days, solution::Part};
+
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "overflow: 18446744073709551615 + 1 doesn't fit in u64")]
+    fn adding() {
+        checked::add(u64::MAX, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow: 0 - 1 doesn't fit in usize")]
+    fn subtracting() {
+        checked::sub(0usize, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow: 4294967296 * 4294967296 doesn't fit in i64")]
+    fn multiplying() {
+        checked::mul(1i64 << 32, 1 << 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow: 1 / 0 doesn't fit in isize")]
+    fn dividing_by_zero() {
+        checked::div(1isize, 0);
+    }
+
+    /// Solve `part` of `day` with `input`, which should overflow.
+    fn solve(day: usize, part: Part, input: &str) {
+        let parsed = days::get(day).unwrap().parse_input(input).unwrap();
+        parsed.solve(part);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow: 4294967296 * 4294967296 doesn't fit in isize")]
+    fn day21() {
+        solve(
+            21,
+            Part::One,
+            "root: abcd * efgh\nabcd: 4294967296\nefgh: humn + ijkl\nijkl: 4294967291\nhumn: 5\n",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow:")]
+    fn day25() {
+        solve(25, Part::One, "2222222222222222222222222222\n");
+    }
+}
@@ -0,0 +1,85 @@
+//! Shared by the regression tests: reading a file of known answers, and checking a day's solver
+//! against it.
+
+// each test crate uses its own share of these
+#![allow(dead_code)]
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use aoc::{
+    days,
+    solution::{Answer, Part},
+};
+
+/// Known answers, keyed by day and part number.
+pub type Answers = HashMap<(usize, usize), Answer>;
+
+/// Path to `relative` inside this crate.
+pub fn crate_path(relative: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+/// Read an answers file, which has one `DAY PART: ANSWER` per line. Pictures start on the next
+/// line instead, with each row indented by four spaces, and lines starting with `#` are comments.
+pub fn read_answers(relative: &str) -> Answers {
+    let path = crate_path(relative);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("reading {}: {}", path.display(), err));
+    let mut answers = Answers::new();
+    let mut last = None;
+    for line in text.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(row) = line.strip_prefix("    ") {
+            match last.and_then(|key| answers.get_mut(&key)) {
+                Some(Answer::Lines(rows)) => rows.push(row.to_string()),
+                _ => panic!("{}: picture row `{}` has no answer", path.display(), line),
+            }
+            continue;
+        }
+        let (key, answer) = line.split_once(':').unwrap_or_else(|| {
+            panic!(
+                "{}: expected `DAY PART: ANSWER`, found `{}`",
+                path.display(),
+                line
+            )
+        });
+        let key = match key.split_whitespace().collect::<Vec<_>>()[..] {
+            [day, part] => (day.parse().unwrap(), part.parse().unwrap()),
+            _ => panic!("{}: expected `DAY PART`, found `{}`", path.display(), key),
+        };
+        let answer = match answer.trim() {
+            "" => Answer::Lines(vec![]),
+            answer => Answer::Line(answer.to_string()),
+        };
+        answers.insert(key, answer);
+        last = Some(key);
+    }
+    answers
+}
+
+/// Solve day `number` for `input`, checking every part that has a known answer.
+pub fn check(number: usize, input: &str, answers: &Answers) {
+    let day = days::get(number).expect("day has been solved");
+    let parsed = day
+        .parse_input(input)
+        .unwrap_or_else(|err| panic!("error: {}", err));
+    for (part, n) in [(Part::One, 1), (Part::Two, 2)] {
+        if let Some(expected) = answers.get(&(number, n)) {
+            assert_eq!(
+                parsed.solve(part).as_ref(),
+                Some(expected),
+                "day {} part {}",
+                number,
+                part
+            );
+        }
+    }
+}
+
+/// Read `relative` inside this crate.
+pub fn read_input(relative: &str) -> String {
+    let path = crate_path(relative);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("reading {}: {}", path.display(), err))
+}
@@ -0,0 +1,82 @@
+//! Finding cycles in a sequence with a known run-in and period, and extrapolating along it.
+
+use aoc::util::cycle::{find_cycle, Cycle};
+
+/// Counts 0, 1, 2, then goes round 3, 4, 5, 6 forever: three steps before the cycle, which is
+/// four long.
+fn step(x: &mut u64) {
+    *x = if *x < 6 { *x + 1 } else { 3 };
+}
+
+/// The running total of the sequence after each step, up to `steps`, which grows by
+/// 3 + 4 + 5 + 6 = 18 each time round.
+fn totals(steps: usize) -> Vec<u64> {
+    let mut x = 0;
+    let mut totals = vec![0];
+    for _ in 0..steps {
+        step(&mut x);
+        totals.push(totals.last().unwrap() + x);
+    }
+    totals
+}
+
+#[test]
+fn finds_the_cycle() {
+    let mut x = 0;
+    let cycle = find_cycle(&mut x, 100, step, |&x| x);
+    assert_eq!(
+        cycle,
+        Some(Cycle {
+            start: 3,
+            length: 4
+        })
+    );
+    // left at the end of the first time round, which is back at the start of the cycle
+    assert_eq!(x, 3);
+
+    // giving up before the first repeat
+    assert_eq!(find_cycle(&mut 0, 6, step, |&x| x), None);
+    assert_eq!(find_cycle(&mut 0, 7, step, |&x| x), cycle);
+    // a key that only tells some states apart finds a cycle among those
+    assert_eq!(
+        find_cycle(&mut 0, 100, step, |&x| x % 2),
+        Some(Cycle {
+            start: 0,
+            length: 2
+        })
+    );
+}
+
+#[test]
+fn equivalent_steps() {
+    let cycle = Cycle {
+        start: 3,
+        length: 4,
+    };
+    let equivalents: Vec<usize> = (0..12).map(|n| cycle.equivalent(n)).collect();
+    assert_eq!(equivalents, [0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3]);
+    assert_eq!(cycle.equivalent(1_000_000_000_003), 3);
+    assert_eq!(cycle.equivalent(1_000_000_000_002), 6);
+}
+
+#[test]
+fn extrapolates() {
+    let cycle = Cycle {
+        start: 3,
+        length: 4,
+    };
+    // only the run-in and the first time round are needed
+    let known = totals(7);
+    let all = totals(200);
+    for (target, &total) in all.iter().enumerate() {
+        assert_eq!(cycle.extrapolate(&known, target), total, "{}", target);
+    }
+    // 1 + 2 + 3, then 18 for every full time round, then what is left of the last
+    let target = 1_000_000_000_000;
+    let rounds = (target - 3) as u64 / 4;
+    let left_over = [0, 4, 4 + 5, 4 + 5 + 6][(target - 3) % 4];
+    assert_eq!(
+        cycle.extrapolate(&known, target),
+        6 + 18 * rounds + left_over
+    );
+}
@@ -0,0 +1,34 @@
+//! Every day against the example in its puzzle, from `tests/examples`, with the parameters the
+//! examples use from `tests/examples/aoc.toml`.
+
+mod common;
+
+use std::sync::Once;
+
+use aoc::params;
+
+static PARAMS: Once = Once::new();
+
+fn check_example(day: usize) {
+    PARAMS.call_once(|| params::load(&common::crate_path("tests/examples/aoc.toml")).unwrap());
+    let answers = common::read_answers("tests/examples/answers.txt");
+    let input = common::read_input(&format!("tests/examples/day{}.txt", day));
+    common::check(day, &input, &answers);
+}
+
+macro_rules! examples {
+    ($($name:ident: $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_example($day);
+            }
+        )*
+    };
+}
+
+examples!(
+    day1: 1, day2: 2, day3: 3, day4: 4, day5: 5, day6: 6, day7: 7, day8: 8, day9: 9,
+    day10: 10, day11: 11, day12: 12, day13: 13, day14: 14, day15: 15, day16: 16, day17: 17,
+    day18: 18, day19: 19, day20: 20, day21: 21, day22: 22, day23: 23, day24: 24, day25: 25,
+);
@@ -0,0 +1,86 @@
+//! Grids: reading character maps, looking along rows, columns and diagonals, and turning them
+//! over.
+
+use aoc::util::{grid::Grid, input};
+
+fn parse(text: &str) -> Grid<char> {
+    Grid::parse(text, &input::lines(text), "anything", Some).unwrap()
+}
+
+fn cells<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+    cells.collect()
+}
+
+#[test]
+fn parsing() {
+    let grid = parse("abc\ndef\n");
+    assert_eq!((grid.height(), grid.width()), (2, 3));
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(grid.to_string(), "abc\ndef");
+
+    let text = "abc\nde\n";
+    let err = Grid::parse(text, &input::lines(text), "anything", Some).unwrap_err();
+    assert_eq!((err.line, err.text.as_str()), (2, "de"));
+    let text = "abc\ndef\n";
+    let err = Grid::parse(text, &input::lines(text), "a letter before e", |c| {
+        (c < 'e').then_some(c)
+    })
+    .unwrap_err();
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "e"));
+
+    // short rows are padded out to the widest
+    let text = "  ab\nc\n abcde\n";
+    let ragged = Grid::parse_ragged(text, &input::lines(text), ' ', "anything", Some).unwrap();
+    assert_eq!((ragged.height(), ragged.width()), (3, 6));
+    assert_eq!(ragged.to_string(), "  ab  \nc     \n abcde");
+
+    let empty = parse("");
+    assert_eq!((empty.height(), empty.width()), (0, 0));
+    assert_eq!(empty.rows().count(), 0);
+    assert_eq!(empty.column(0).count(), 0);
+}
+
+#[test]
+fn views() {
+    let grid = parse("abcd\nefgh\nijkl\n");
+    assert_eq!(grid.row(1), ['e', 'f', 'g', 'h']);
+    assert_eq!(cells(grid.column(2)), "cgk");
+    assert_eq!(cells(grid.column(4)), "");
+    assert_eq!(cells(grid.diagonal((1, 2))), "bgl");
+    assert_eq!(cells(grid.diagonal((2, 0))), "i");
+    assert_eq!(cells(grid.diagonal((0, 0))), "afk");
+    assert_eq!(cells(grid.anti_diagonal((1, 2))), "dgj");
+    assert_eq!(cells(grid.anti_diagonal((0, 0))), "a");
+    assert_eq!(cells(grid.anti_diagonal((2, 3))), "l");
+    assert_eq!(cells(grid.anti_diagonal((2, 0))), "cfi");
+
+    let ray: Vec<_> = grid.ray((0, 0), (1, 1)).collect();
+    assert_eq!(ray, [(1, 1), (2, 2)]);
+    let mut neighbours: Vec<_> = grid.neighbours4((0, 3)).collect();
+    neighbours.sort();
+    assert_eq!(neighbours, [(0, 2), (1, 3)]);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8((2, 0)).count(), 3);
+}
+
+#[test]
+fn turning_over() {
+    let grid = parse("abc\ndef\n");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+    // every transform is undone by itself or its opposite
+    assert_eq!(grid.transpose().transpose(), grid);
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    assert_eq!(grid.rotate_ccw().rotate_cw(), grid);
+    assert_eq!(grid.flip_horizontal().flip_horizontal(), grid);
+    assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+    let half_turn = grid.rotate_cw().rotate_cw();
+    assert_eq!(half_turn, grid.flip_horizontal().flip_vertical());
+    assert_eq!(half_turn.rotate_cw().rotate_cw(), grid);
+    assert_eq!(grid.rotate_cw(), grid.transpose().flip_horizontal());
+}
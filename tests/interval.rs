@@ -0,0 +1,139 @@
+//! Interval sets, on small cases with the edges spelled out and against a set of every value on
+//! random ones.
+
+// a set of one range is written as an array of one range
+#![allow(clippy::single_range_in_vec_init)]
+
+// the generator the oracles use, of which these tests only need some
+#[allow(dead_code)]
+#[path = "oracles/rng.rs"]
+mod rng;
+
+use std::{collections::BTreeSet, ops::Range};
+
+use aoc::util::interval::IntervalSet;
+use rng::Rng;
+
+fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+    let mut set = IntervalSet::new();
+    for range in ranges {
+        set.insert(range.clone());
+    }
+    set
+}
+
+#[test]
+fn inserts_merge() {
+    // touching ranges are merged, whichever comes first
+    assert_eq!(set(&[0..3, 3..5]).ranges(), [0..5]);
+    assert_eq!(set(&[3..5, 0..3]).ranges(), [0..5]);
+    // a gap of one value is kept
+    assert_eq!(set(&[0..3, 4..5]).ranges(), [0..3, 4..5]);
+    // overlapping, inside, and bridging several ranges at once
+    assert_eq!(set(&[0..4, 2..6]).ranges(), [0..6]);
+    assert_eq!(set(&[0..10, 2..6]).ranges(), [0..10]);
+    assert_eq!(set(&[0..2, 4..6, 8..10, 1..9]).ranges(), [0..10]);
+    assert_eq!(set(&[0..2, 4..6, 8..10, 2..4]).ranges(), [0..6, 8..10]);
+    // empty ranges add nothing
+    assert!(set(&[3..3]).is_empty());
+    assert_eq!(set(&[0..2, 5..5, 2..2]).ranges(), [0..2]);
+
+    let collected: IntervalSet<i32> = [8..10, 0..2, 2..4, 5..5, 3..6].into_iter().collect();
+    assert_eq!(collected, set(&[8..10, 0..2, 2..4, 5..5, 3..6]));
+    assert_eq!(collected.len(), 8);
+}
+
+#[test]
+fn removes_split() {
+    let mut split = set(&[0..10]);
+    split.remove(3..5);
+    assert_eq!(split.ranges(), [0..3, 5..10]);
+    assert!(split.contains(2) && !split.contains(3) && !split.contains(4) && split.contains(5));
+
+    // the ends of the outermost ranges are all that is left of several
+    let mut several = set(&[0..4, 6..8, 10..14]);
+    several.remove(2..12);
+    assert_eq!(several.ranges(), [0..2, 12..14]);
+
+    // removing exactly a range, or what only touches it, or nothing
+    let mut exact = set(&[0..4, 6..8]);
+    exact.remove(6..8);
+    assert_eq!(exact.ranges(), [0..4]);
+    exact.remove(4..6);
+    exact.remove(-3..0);
+    exact.remove(2..2);
+    assert_eq!(exact.ranges(), [0..4]);
+    exact.remove(-5..5);
+    assert!(exact.is_empty());
+}
+
+#[test]
+fn complement_and_intersection() {
+    let scanned = set(&[-2..3, 5..8]);
+    // the bounds cut the ends off, and ranges at the bounds leave nothing there
+    assert_eq!(scanned.complement(0..10).ranges(), [3..5, 8..10]);
+    assert_eq!(scanned.complement(-2..8).ranges(), [3..5]);
+    assert_eq!(scanned.complement(3..5).ranges(), [3..5]);
+    assert!(scanned.complement(5..8).is_empty());
+    assert!(scanned.complement(4..4).is_empty());
+    assert_eq!(IntervalSet::new().complement(0..4).ranges(), [0..4]);
+
+    let other = set(&[0..6, 7..9]);
+    assert_eq!(scanned.intersection(&other).ranges(), [0..3, 5..6, 7..8]);
+    assert_eq!(scanned.intersection(&other), other.intersection(&scanned));
+    assert!(scanned.intersection(&set(&[3..5, 8..9])).is_empty());
+    assert_eq!(scanned.union(&other).ranges(), [-2..9]);
+}
+
+#[test]
+fn gaps() {
+    let gaps = |set: &IntervalSet<i32>| set.gaps().collect::<Vec<_>>();
+    assert_eq!(gaps(&IntervalSet::new()), []);
+    assert_eq!(gaps(&set(&[2..5])), []);
+    // touching ranges merge, leaving nothing between them
+    assert_eq!(gaps(&set(&[0..3, 3..5])), []);
+    assert_eq!(gaps(&set(&[0..3, 4..5, 8..9])), [3..4, 5..8]);
+}
+
+/// The values in `set` between the bounds the random tests use.
+fn values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+    (-5..50).filter(|&value| set.contains(value)).collect()
+}
+
+fn random_range(rng: &mut Rng) -> Range<i32> {
+    let start = rng.range(0..=40) as i32;
+    start..start + rng.range(0..=6) as i32
+}
+
+#[test]
+fn random_against_values() {
+    let mut rng = Rng::new(0);
+    for _ in 0..200 {
+        let (mut set, mut expected) = (IntervalSet::new(), BTreeSet::new());
+        let mut other = IntervalSet::new();
+        for _ in 0..rng.range(0..=12) {
+            let range = random_range(&mut rng);
+            if rng.one_in(3) {
+                expected.retain(|value| !range.contains(value));
+                set.remove(range);
+            } else {
+                expected.extend(range.clone());
+                set.insert(range);
+            }
+            other.insert(random_range(&mut rng));
+        }
+        assert_eq!(values(&set), expected);
+        assert_eq!(set.len() as usize, expected.len());
+        // the ranges are sorted and separated by real gaps
+        for pair in set.ranges().windows(2) {
+            assert!(pair[0].end < pair[1].start, "{:?}", set);
+        }
+        assert!(set.ranges().iter().all(|range| !range.is_empty()));
+
+        let others = values(&other);
+        assert_eq!(values(&set.union(&other)), &expected | &others);
+        assert_eq!(values(&set.intersection(&other)), &expected & &others);
+        let bounds: BTreeSet<i32> = (5..30).collect();
+        assert_eq!(values(&set.complement(5..30)), &bounds - &expected);
+    }
+}
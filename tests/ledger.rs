@@ -0,0 +1,177 @@
+//! The answer ledger, and `aoc run` checking answers against it.
+
+mod common;
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use aoc::{
+    ledger::{Guess, Ledger, Verdict, LEDGER_VAR},
+    solution::Part,
+};
+
+fn guess(value: &str, verdict: Verdict) -> Guess {
+    Guess {
+        day: 1,
+        part: Part::One,
+        value: String::from(value),
+        verdict,
+    }
+}
+
+fn ledger(guesses: &[(&str, Verdict)]) -> Ledger {
+    Ledger {
+        guesses: guesses
+            .iter()
+            .map(|&(value, verdict)| guess(value, verdict))
+            .collect(),
+    }
+}
+
+/// A fresh ledger file for a test called `name`.
+fn ledger_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("aoc-ledger-{}-{}.toml", name, process::id()));
+    let _ = fs::remove_file(&path);
+    path
+}
+
+/// Run `aoc` with `args` and the ledger at `path`, returning whether it succeeded.
+fn aoc(path: &Path, args: &[&str]) -> bool {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env(LEDGER_VAR, path)
+        .current_dir(common::crate_path(""))
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[test]
+fn round_trip() {
+    let ledger = Ledger {
+        guesses: vec![
+            guess("1234", Verdict::TooLow),
+            Guess {
+                day: 10,
+                part: Part::Two,
+                value: String::from("a \"quoted\" \\ value"),
+                verdict: Verdict::Wrong,
+            },
+            guess("2345", Verdict::Correct),
+        ],
+    };
+    assert_eq!(Ledger::parse(&ledger.to_toml()), Ok(ledger));
+    assert_eq!(Ledger::parse(""), Ok(Ledger::default()));
+}
+
+#[test]
+fn bad_lines() {
+    let cases = [
+        ("day = 1", "line 1: expected [[guess]]"),
+        ("[[guess]]\nday = one", "line 2: day should be a number"),
+        ("[[guess]]\n\npart = 3", "line 3: part should be 1 or 2"),
+        ("[[guess]]\nvalue = 12", "line 2: value should be a string"),
+        ("[[guess]]\nanswer = \"12\"", "line 2: unknown key 'answer'"),
+        ("[[guess]]\nday 1", "line 2: expected `key = value`"),
+        (
+            "[[guess]]\nverdict = \"close\"",
+            "line 2: 'close' is not a verdict, expected correct, wrong, too_low or too_high",
+        ),
+        (
+            "# guesses\n[[guess]]\nday = 1\npart = 1\nvalue = \"12\"",
+            "line 2: the guess has no verdict",
+        ),
+    ];
+    for (text, message) in cases {
+        assert_eq!(Ledger::parse(text), Err(String::from(message)), "{}", text);
+    }
+}
+
+#[test]
+fn contradictions() {
+    let hints = ledger(&[
+        ("100", Verdict::TooLow),
+        ("200", Verdict::TooHigh),
+        ("150", Verdict::Wrong),
+    ]);
+    let check = |value| {
+        let guesses = hints.contradictions(1, Part::One, value);
+        guesses.iter().map(ToString::to_string).collect::<Vec<_>>()
+    };
+    assert!(check("120").is_empty());
+    assert_eq!(check("100"), ["100 was too low"]);
+    assert_eq!(check("99"), ["100 was too low"]);
+    assert_eq!(check("250"), ["200 was too high"]);
+    assert_eq!(check("150"), ["150 was wrong"]);
+    // letters can't be too high or too low
+    assert!(check("EALGULPG").is_empty());
+    // nor can other days' answers
+    assert!(hints.contradictions(2, Part::One, "99").is_empty());
+    assert!(hints.contradictions(1, Part::Two, "99").is_empty());
+
+    let solved = ledger(&[("120", Verdict::Correct)]);
+    assert!(solved.contradictions(1, Part::One, "120").is_empty());
+    let answer = solved.contradictions(1, Part::One, "121");
+    assert_eq!(answer, [&solved.guesses[0]]);
+    assert_eq!(answer[0].to_string(), "120 was confirmed as the answer");
+}
+
+#[test]
+fn records() {
+    let mut ledger = Ledger::default();
+    ledger.record(guess("100", Verdict::Wrong)).unwrap();
+    ledger.record(guess("120", Verdict::Correct)).unwrap();
+    assert_eq!(
+        ledger.record(guess("130", Verdict::Correct)),
+        Err(String::from(
+            "day 1 part 1 already has a correct answer, 120"
+        ))
+    );
+    // the same value again changes its verdict
+    ledger.record(guess("100", Verdict::TooLow)).unwrap();
+    assert_eq!(
+        ledger.guesses,
+        [
+            guess("100", Verdict::TooLow),
+            guess("120", Verdict::Correct)
+        ]
+    );
+}
+
+#[test]
+fn runs_against_the_ledger() {
+    let path = ledger_path("run");
+    assert!(aoc(&path, &["run", "-d", "1", "-p", "1"]));
+
+    let record = ["answer", "record", "-d", "1", "-p", "1", "--value"];
+    assert!(aoc(
+        &path,
+        &[&record[..], &["69000", "--verdict", "too_low"]].concat()
+    ));
+    assert!(aoc(&path, &["run", "-d", "1", "-p", "1"]));
+    assert!(aoc(
+        &path,
+        &[&record[..], &["69528", "--verdict", "too_high"]].concat()
+    ));
+    assert!(!aoc(&path, &["run", "-d", "1", "-p", "1"]));
+    // part 2 has no guesses
+    assert!(aoc(&path, &["run", "-d", "1", "-p", "2"]));
+
+    let ledger = Ledger::load(&path).unwrap();
+    assert_eq!(
+        ledger.guesses,
+        [
+            guess("69000", Verdict::TooLow),
+            guess("69528", Verdict::TooHigh)
+        ]
+    );
+    assert!(!aoc(
+        &path,
+        &[&record[..], &["69528", "--verdict", "maybe"]].concat()
+    ));
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,191 @@
+//! The days used as a library: each day's parsed input and typed answers, without going through
+//! the runner's registry.
+
+mod common;
+
+use aoc::solution::Solution;
+use aoc::{day1, day10, day11, day13, day15, day20, day21, day25, day7};
+
+fn example(day: usize) -> String {
+    common::read_input(&format!("tests/examples/day{}.txt", day))
+}
+
+#[test]
+fn typed_answers() {
+    let calories = day1::Day1::parse(&example(1)).unwrap();
+    assert_eq!(day1::part1(&calories), 24000);
+    assert_eq!(day1::part2(&calories), 45000);
+
+    let sizes = day7::Day7::parse(&example(7)).unwrap();
+    assert_eq!(day7::part1(&sizes), 95437);
+    assert_eq!(day7::part2(&sizes), Some(24933642));
+
+    let commands = day10::Day10::parse(&example(10)).unwrap();
+    assert_eq!(day10::part1(&commands), 13140);
+    assert_eq!(day10::part2(&commands)[0], "##..".repeat(10));
+
+    let file = day20::Day20::parse(&example(20)).unwrap();
+    assert_eq!(day20::part1(&file), 3);
+    assert_eq!(day20::part2(&file), 1623178306);
+}
+
+#[test]
+fn file_tree() {
+    let mut sizes = day7::file_tree(&example(7))
+        .unwrap()
+        .traverse_and_store_dirsize();
+    sizes.sort();
+    let expected = [
+        ("/", 48381165),
+        ("/a", 94853),
+        ("/a/e", 584),
+        ("/d", 24933642),
+    ];
+    assert_eq!(
+        sizes,
+        expected.map(|(path, size)| (String::from(path), size))
+    );
+
+    let mut tree = day7::FileTree::empty();
+    tree.change_directory(String::from("a"));
+    tree.add_file((100, String::from("x")));
+    tree.change_directory(String::from("b"));
+    tree.add_file((20, String::from("y")));
+    tree.move_out();
+    tree.move_out();
+    tree.add_file((3, String::from("z")));
+    let mut sizes = tree.traverse_and_store_dirsize();
+    sizes.sort_by_key(|(_, size)| *size);
+    let sizes: Vec<usize> = sizes.into_iter().map(|(_, size)| size).collect();
+    assert_eq!(sizes, [20, 120, 123]);
+}
+
+#[test]
+fn packets() {
+    use day13::{compare_packetentry, parse_list_from_string};
+    let in_order = |left, right| {
+        compare_packetentry(parse_list_from_string(left), parse_list_from_string(right))
+    };
+    assert_eq!(in_order("[1,1,3,1,1]", "[1,1,5,1,1]"), Some(true));
+    assert_eq!(in_order("[[1],[2,3,4]]", "[[1],4]"), Some(true));
+    assert_eq!(in_order("[9]", "[[8,7,6]]"), Some(false));
+    assert_eq!(in_order("[[[]]]", "[[]]"), Some(false));
+    assert_eq!(in_order("[10,[2]]", "[10,[2]]"), None);
+}
+
+#[test]
+fn scanned_on_row() {
+    let (sensors, beacons) = day15::Day15::parse(&example(15)).unwrap();
+    let scanned = day15::scanned_on_row(&sensors, 10);
+    assert_eq!(scanned.len(), 27);
+    assert!(scanned.contains(-2) && scanned.contains(24) && !scanned.contains(25));
+    // the one beacon on the row is closest to two of the sensors
+    assert!(beacons.iter().all(|beacon| beacon.y != 10 || beacon.x == 2));
+    // the distress beacon is the only square in the search region that isn't scanned
+    assert!(!day15::scanned_on_row(&sensors, 11).contains(14));
+    assert!(day15::scanned_on_row(&sensors, 11).contains(13));
+}
+
+#[test]
+fn snafu() {
+    let numbers = day25::Day25::parse(&example(25)).unwrap();
+    assert_eq!(day25::part1(&numbers).to_string(), "2=-1=0");
+    for n in [0_i64, 1, 3, 2022, 314159265, -7] {
+        let snafu = day25::SNAFU::from_decimal(&n);
+        assert_eq!(snafu.to_decimal::<i64>(), n, "{}", snafu);
+    }
+    assert_eq!(day25::SNAFU::from_decimal(&2022_i64).to_string(), "1=11-2");
+    assert_eq!(day25::SNAFU::sum::<i64>(&numbers).to_decimal::<i64>(), 4890);
+}
+
+#[test]
+fn empty_input() {
+    for day in [7, 12, 14, 15, 17, 20, 23, 24] {
+        let err = aoc::days::get(day).unwrap().parse_input("").err();
+        assert_eq!(
+            err.map(|err| (err.line, err.text)),
+            Some((1, String::new())),
+            "day {}",
+            day
+        );
+    }
+    let err = day20::Day20::parse("1\n2\n-3\n").unwrap_err();
+    assert_eq!(err.line, 4);
+}
+
+#[test]
+fn unsolvable_monkeys() {
+    let cases = [
+        (
+            "root: aaaa + humn\nhumn: 5\n",
+            1,
+            "aaaa",
+            "the name of a monkey",
+        ),
+        (
+            "root: aaaa + humn\naaaa: bbbb + humn\nbbbb: aaaa - humn\nhumn: 5\n",
+            3,
+            "aaaa",
+            "a monkey that isn't waiting on this one",
+        ),
+        ("root: 5\nhumn: 5\n", 1, "5", "an operation for root"),
+        (
+            "root: aaaa + bbbb\naaaa: 1\nbbbb: 2\nhumn: 5\n",
+            1,
+            "aaaa + bbbb",
+            "an operation that waits on humn",
+        ),
+        (
+            "root: aaaa / bbbb\naaaa: humn + humn\nbbbb: humn - humn\nhumn: 5\n",
+            1,
+            "bbbb",
+            "a monkey that doesn't yell 0",
+        ),
+    ];
+    for (input, line, text, expected) in cases {
+        let err = day21::Day21::parse(input).unwrap_err();
+        assert_eq!(
+            (err.line, err.text.as_str(), err.expected.as_str()),
+            (line, text, expected),
+            "{:?}",
+            input
+        );
+    }
+
+    // humn times zero is zero whatever humn yells
+    let operands = day21::Day21::parse(
+        "root: aaaa + bbbb\naaaa: humn * zero\nzero: cccc - cccc\ncccc: 3\nbbbb: 4\nhumn: 5\n",
+    )
+    .unwrap();
+    assert_eq!(day21::part1::<isize>(&operands), 4);
+    assert_eq!(day21::part2::<isize>(&operands), None);
+}
+
+#[test]
+fn unplayable_monkeys() {
+    let monkeys = example(11);
+    let cases = [
+        (
+            monkeys.replacen("monkey 3", "monkey 4", 1),
+            6,
+            "a monkey numbered below 4",
+        ),
+        (
+            monkeys.replacen("by 23", "by 0", 1),
+            4,
+            "a divisor other than 0",
+        ),
+        (
+            monkeys.split("\n\n").next().unwrap().to_string(),
+            6,
+            "at least two monkeys",
+        ),
+    ];
+    for (input, line, expected) in cases {
+        let err = day11::Day11::parse(&input).err();
+        assert_eq!(
+            err.map(|err| (err.line, err.expected)),
+            Some((line, expected.to_string()))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Reading log levels from `AOC_LOG` and the `-v` and `-q` flags.
+
+mod common;
+
+use std::process::Command;
+
+use aoc::util::log::{Level, LOG_VAR};
+
+#[test]
+fn level_names() {
+    for (name, level) in [
+        ("error", Level::Error),
+        ("info", Level::Info),
+        ("DEBUG", Level::Debug),
+        (" trace\n", Level::Trace),
+    ] {
+        assert_eq!(name.parse(), Ok(level), "{:?}", name);
+    }
+    for name in ["loud", "", "warn", "2"] {
+        assert_eq!(
+            name.parse::<Level>(),
+            Err(format!(
+                "'{}' is not a log level, expected error, info, debug or trace",
+                name
+            ))
+        );
+    }
+}
+
+#[test]
+fn flags() {
+    let after = |level: Level, flag| level.after_flag(flag);
+    assert_eq!(after(Level::Info, "-v"), Some(Level::Debug));
+    assert_eq!(after(Level::Info, "--verbose"), Some(Level::Debug));
+    assert_eq!(after(Level::Info, "-vv"), Some(Level::Trace));
+    assert_eq!(after(Level::Info, "-q"), Some(Level::Error));
+    assert_eq!(after(Level::Info, "--quiet"), Some(Level::Error));
+    // the level stops at either end, one flag at a time
+    assert_eq!(after(Level::Trace, "-v"), Some(Level::Trace));
+    assert_eq!(after(Level::Error, "-qqq"), Some(Level::Error));
+    assert_eq!(after(Level::Error, "-qv"), Some(Level::Info));
+    assert_eq!(after(Level::Trace, "-vq"), Some(Level::Debug));
+    for flag in ["-", "--", "-vx", "-V", "--verbose=2", "v", "--vv", "-d"] {
+        assert_eq!(after(Level::Info, flag), None, "{:?}", flag);
+    }
+}
+
+/// What `aoc` writes to stderr solving the day 15 example with `AOC_LOG` set to `env`, if
+/// anything, and `flags`.
+fn stderr(env: Option<&str>, flags: &[&str]) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(["run", "-d", "15", "-i", "tests/examples/day15.txt"])
+        .args(flags)
+        .current_dir(common::crate_path(""))
+        .env_remove(LOG_VAR);
+    if let Some(env) = env {
+        command.env(LOG_VAR, env);
+    }
+    String::from_utf8(command.output().unwrap().stderr).unwrap()
+}
+
+#[test]
+fn levels_from_the_environment() {
+    assert!(!stderr(None, &[]).contains("[debug]"));
+    assert!(stderr(None, &["-v"]).contains("[debug]"));
+    assert!(stderr(Some("debug"), &[]).contains("[debug]"));
+    assert!(!stderr(Some("debug"), &["--quiet"]).contains("[debug]"));
+    assert!(stderr(Some("error"), &["-v", "-v"]).contains("[debug]"));
+
+    // a name that isn't a level is reported, and the level starts from info
+    let unknown = stderr(Some("loud"), &["-v"]);
+    assert!(unknown.starts_with(
+        "[error] AOC_LOG: 'loud' is not a log level, expected error, info, debug or trace\n"
+    ));
+    assert!(unknown.contains("[debug]"));
+}
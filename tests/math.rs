@@ -0,0 +1,16 @@
+//! Greatest common divisors and least common multiples.
+
+use aoc::util::math::{gcd, lcm};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(7, 5), 1);
+    assert_eq!(gcd(9, 0), 9);
+    assert_eq!(lcm(4, 6), 12);
+    assert_eq!(lcm(120, 25), 600);
+    assert_eq!(lcm(7, 1), 7);
+    assert_eq!(lcm(5, 5), 5);
+    // the divisors of the day 11 example
+    assert_eq!([23, 19, 13, 17].into_iter().fold(1, lcm), 96577);
+}
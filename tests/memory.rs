@@ -0,0 +1,65 @@
+//! Counting allocations. The counts are only kept with the `count-allocs` feature, so most of
+//! these tests need `cargo test --features count-allocs`.
+
+mod common;
+
+use std::{hint::black_box, process::Command};
+
+use aoc::memory;
+
+#[test]
+fn formats_bytes() {
+    assert_eq!(memory::format_bytes(0), "0 B");
+    assert_eq!(memory::format_bytes(1023), "1023 B");
+    assert_eq!(memory::format_bytes(1536), "1.50 KiB");
+    assert_eq!(memory::format_bytes(5 << 30), "5.00 GiB");
+}
+
+/// The summary `aoc run` prints for day 2's example.
+fn summary() -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "-d", "2", "-i", "tests/examples/day2.txt"])
+        .current_dir(common::crate_path(""))
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[cfg(not(feature = "count-allocs"))]
+#[test]
+fn counts_nothing() {
+    let (_, usage) = memory::measure(|| black_box(vec![0u8; 1000]));
+    assert_eq!(usage, memory::Usage::default());
+    assert!(summary().contains("Day  Stage          Time  Status"));
+}
+
+#[cfg(feature = "count-allocs")]
+#[test]
+fn counts_allocations() {
+    // other tests could be allocating at the same time, so these are only lower bounds
+    let (_, usage) = memory::measure(|| {
+        let big = black_box(vec![0u64; 1000]);
+        drop(big);
+        let mut grown: Vec<u64> = Vec::new();
+        for i in 0..100 {
+            grown.push(black_box(i));
+        }
+        grown
+    });
+    assert!(usage.allocations >= 2, "{:?}", usage);
+    assert!(usage.bytes >= 8800, "{:?}", usage);
+    assert!(usage.peak >= 8000, "{:?}", usage);
+    let (_, usage) = memory::measure(|| black_box(1 + 1));
+    assert!(usage.peak <= usage.bytes, "{:?}", usage);
+}
+
+#[cfg(feature = "count-allocs")]
+#[test]
+fn reports_allocations() {
+    let summary = summary();
+    assert!(
+        summary.contains("Day  Stage          Time    Allocs    Allocated         Peak  Status"),
+        "{}",
+        summary
+    );
+}
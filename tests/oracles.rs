@@ -0,0 +1,122 @@
+//! Differential tests: random small inputs, solved by both a day's solver and a slow reference
+//! solver that is simple enough to be obviously right, which must agree.
+//!
+//! Each day's module makes up valid inputs from a [`Rng`] and solves them the slow way. Every case
+//! has its own seed, and a disagreement reports the seed along with the input. Setting
+//! `AOC_SEED` runs only the case with that seed, to look at it more closely.
+
+mod oracles {
+    pub mod day15;
+    pub mod day16;
+    pub mod day17;
+    pub mod day21;
+    pub mod rng;
+}
+
+use std::env;
+
+use aoc::{
+    days, params,
+    solution::{Answer, Part},
+};
+use oracles::rng::Rng;
+
+/// Environment variable naming a single seed to run.
+const SEED_VAR: &str = "AOC_SEED";
+
+/// A day's generator and its reference solver.
+struct Oracle {
+    day: usize,
+    /// How many cases to run, as many as the solver can get through in a second or two.
+    cases: u64,
+    /// Parameters to solve with instead of the real puzzle's, for inputs too small for those.
+    params: &'static [(&'static str, i64)],
+    generate: fn(&mut Rng) -> String,
+    /// The answers to whichever parts can be checked.
+    reference: fn(&str) -> Vec<(Part, Answer)>,
+}
+
+/// Run every case of `oracle`, panicking with the seed and the input at the first disagreement.
+fn check(oracle: Oracle) {
+    let day = days::get(oracle.day).unwrap();
+    // only this day reads its parameters, so the tests of other days running alongside don't
+    // mind them being set
+    for &(key, value) in oracle.params {
+        let param = params::find(key).unwrap();
+        params::set(param, param.check(value).unwrap());
+    }
+    let seeds = match env::var(SEED_VAR) {
+        Ok(seed) => {
+            let seed = seed.parse().expect("AOC_SEED should be a number");
+            seed..seed + 1
+        }
+        Err(_) => 0..oracle.cases,
+    };
+    for seed in seeds {
+        let input = (oracle.generate)(&mut Rng::new(seed));
+        let parsed = day.parse_input(&input).unwrap_or_else(|err| {
+            panic!(
+                "day {} with seed {} didn't parse: {}\n--- input ---\n{}",
+                oracle.day, seed, err, input
+            )
+        });
+        for (part, expected) in (oracle.reference)(&input) {
+            let answer = parsed.solve(part);
+            assert!(
+                answer.as_ref() == Some(&expected),
+                "day {} part {} with seed {}: the solver says {:?} but the reference says {}\n\
+                 --- input ---\n{}",
+                oracle.day,
+                part,
+                seed,
+                answer,
+                expected,
+                input
+            );
+        }
+    }
+}
+
+#[test]
+fn day15() {
+    check(Oracle {
+        day: 15,
+        cases: 2000,
+        params: oracles::day15::PARAMS,
+        generate: oracles::day15::generate,
+        reference: oracles::day15::reference,
+    });
+}
+
+#[test]
+fn day16() {
+    check(Oracle {
+        day: 16,
+        cases: 1000,
+        params: &[],
+        generate: oracles::day16::generate,
+        reference: oracles::day16::reference,
+    });
+}
+
+#[test]
+fn day17() {
+    check(Oracle {
+        day: 17,
+        cases: 300,
+        params: oracles::day17::PARAMS,
+        generate: oracles::day17::generate,
+        reference: oracles::day17::reference,
+    });
+}
+
+#[test]
+fn day21() {
+    check(Oracle {
+        day: 21,
+        cases: 2000,
+        params: &[],
+        generate: oracles::day21::generate,
+        reference: oracles::day21::reference,
+    });
+}
@@ -0,0 +1,112 @@
+//! Sensors and beacons clustered around the row part 1 asks about and the square part 2 searches,
+//! both made as small as the puzzle's example so that every square in them can be checked one by
+//! one.
+
+use std::fmt::Write;
+
+use aoc::solution::{Answer, Part};
+
+use super::rng::Rng;
+
+/// The row part 1 counts along.
+const PROBE_ROW: i64 = 10;
+
+/// The largest x and y part 2 searches.
+const SEARCH_MAX: i64 = 20;
+
+/// The parameters the generated inputs are solved with.
+pub const PARAMS: &[(&str, i64)] = &[
+    ("day15.probe_row", PROBE_ROW),
+    ("day15.search_max", SEARCH_MAX),
+];
+
+type Point = (i64, i64);
+
+fn manhattan(a: Point, b: Point) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// Whether every sensor's beacon is closer to it than any other beacon, and no beacon is on top
+/// of a sensor, as the puzzle promises.
+fn consistent(pairs: &[(Point, Point)]) -> bool {
+    pairs.iter().all(|&(sensor, beacon)| {
+        let reach = manhattan(sensor, beacon);
+        pairs.iter().all(|&(other_sensor, other)| {
+            other_sensor != beacon && (other == beacon || manhattan(sensor, other) > reach)
+        })
+    })
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let count = rng.range(1..=12) as usize;
+    let mut pairs: Vec<(Point, Point)> = vec![];
+    while pairs.len() < count {
+        let sensor = (
+            rng.range(-5..=SEARCH_MAX + 5),
+            rng.range(-5..=SEARCH_MAX + 5),
+        );
+        // sometimes share a beacon that's already there, as the puzzle often does
+        let beacon = if !pairs.is_empty() && rng.one_in(4) {
+            rng.choose(&pairs).1
+        } else {
+            let reach = rng.range(0..=15);
+            let dx = rng.range(-reach..=reach);
+            let dy = (reach - dx.abs()) * if rng.one_in(2) { 1 } else { -1 };
+            (sensor.0 + dx, sensor.1 + dy)
+        };
+        pairs.push((sensor, beacon));
+        if !consistent(&pairs) {
+            pairs.pop();
+        }
+    }
+    let mut input = String::new();
+    for ((sx, sy), (bx, by)) in pairs {
+        writeln!(
+            input,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sx, sy, bx, by
+        )
+        .unwrap();
+    }
+    input
+}
+
+/// Try every square along the probe row that any sensor could reach, and then every square in
+/// the search region in turn until one is out of reach of every sensor. There may not be one,
+/// in which case part 2 isn't compared.
+pub fn reference(input: &str) -> Vec<(Part, Answer)> {
+    let pairs: Vec<(Point, Point)> = input
+        .lines()
+        .map(|line| {
+            let numbers: Vec<i64> = line
+                .split(|c: char| !(c.is_ascii_digit() || c == '-'))
+                .filter_map(|word| word.parse().ok())
+                .collect();
+            ((numbers[0], numbers[1]), (numbers[2], numbers[3]))
+        })
+        .collect();
+    let reach = pairs
+        .iter()
+        .map(|&(sensor, beacon)| manhattan(sensor, beacon))
+        .max()
+        .unwrap();
+    let min_x = pairs.iter().map(|(sensor, _)| sensor.0).min().unwrap() - reach;
+    let max_x = pairs.iter().map(|(sensor, _)| sensor.0).max().unwrap() + reach;
+    let scanned = |square: Point| {
+        pairs
+            .iter()
+            .any(|&(sensor, beacon)| manhattan(sensor, square) <= manhattan(sensor, beacon))
+    };
+    let no_beacon = (min_x..=max_x)
+        .map(|x| (x, PROBE_ROW))
+        .filter(|&square| pairs.iter().all(|&(_, beacon)| beacon != square) && scanned(square))
+        .count();
+    let mut answers = vec![(Part::One, no_beacon.into())];
+    let distress = (0..=SEARCH_MAX)
+        .flat_map(|y| (0..=SEARCH_MAX).map(move |x| (x, y)))
+        .find(|&square| !scanned(square));
+    if let Some((x, y)) = distress {
+        answers.push((Part::Two, (x * 4_000_000 + y).into()));
+    }
+    answers
+}
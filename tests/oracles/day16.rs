@@ -0,0 +1,199 @@
+//! A handful of valves joined by tunnels, a few of them with some flow.
+
+use std::{collections::HashMap, fmt::Write};
+
+use aoc::solution::{Answer, Part};
+
+use super::rng::Rng;
+
+pub fn generate(rng: &mut Rng) -> String {
+    let count = rng.range(1..=6) as usize;
+    let mut names = vec![String::from("AA")];
+    while names.len() < count {
+        let mut letter = || (b'A' + rng.below(26) as u8) as char;
+        let name: String = [letter(), letter()].into_iter().collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    // a random tree, so that every valve can be reached, and then a few more tunnels
+    let mut tunnels: Vec<Vec<usize>> = vec![vec![]; count];
+    let mut join = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        join(valve, rng.below(valve));
+    }
+    for _ in 0..rng.below(count) {
+        join(rng.below(count), rng.below(count));
+    }
+    let mut order: Vec<usize> = (0..count).collect();
+    rng.shuffle(&mut order);
+
+    let mut input = String::new();
+    for valve in order {
+        let rate = if rng.one_in(3) { 0 } else { rng.range(1..=25) };
+        let to: Vec<&str> = tunnels[valve]
+            .iter()
+            .map(|&other| names[other].as_str())
+            .collect();
+        // a valve with nowhere to go still needs a tunnel, so it leads back to itself
+        let to = if to.is_empty() {
+            vec![names[valve].as_str()]
+        } else {
+            to
+        };
+        let (tunnels, lead, valves) = if to.len() == 1 {
+            ("tunnel", "leads", "valve")
+        } else {
+            ("tunnels", "lead", "valves")
+        };
+        writeln!(
+            input,
+            "Valve {} has flow rate={}; {} {} to {} {}",
+            names[valve],
+            rate,
+            tunnels,
+            lead,
+            valves,
+            to.join(", ")
+        )
+        .unwrap();
+    }
+    input
+}
+
+struct Valves {
+    rates: Vec<u64>,
+    tunnels: Vec<Vec<usize>>,
+    start: usize,
+}
+
+fn read(input: &str) -> Valves {
+    let lines: Vec<Vec<&str>> = input
+        .lines()
+        .map(|line| line.split([' ', ',', '=', ';']).collect())
+        .collect();
+    let index: HashMap<&str, usize> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, words)| (words[1], i))
+        .collect();
+    Valves {
+        rates: lines
+            .iter()
+            .map(|words| words[5].parse().unwrap())
+            .collect(),
+        tunnels: lines
+            .iter()
+            .map(|words| {
+                words[11..]
+                    .iter()
+                    .filter(|word| !word.is_empty())
+                    .map(|word| index[word])
+                    .collect()
+            })
+            .collect(),
+        start: index["AA"],
+    }
+}
+
+/// Everything one person can do with a minute at `valve`: wait, open it if that is worth
+/// doing, or walk down a tunnel. Opening is `None`.
+fn moves(valves: &Valves, valve: usize, open: u64) -> Vec<Option<usize>> {
+    let mut moves = vec![Some(valve)];
+    if valves.rates[valve] > 0 && open & (1 << valve) == 0 {
+        moves.push(None);
+    }
+    moves.extend(valves.tunnels[valve].iter().map(|&to| Some(to)));
+    moves
+}
+
+/// The most pressure released from here, trying every move each minute.
+fn alone(
+    valves: &Valves,
+    at: usize,
+    minutes: u64,
+    open: u64,
+    seen: &mut HashMap<(usize, u64, u64), u64>,
+) -> u64 {
+    if minutes == 0 {
+        return 0;
+    }
+    if let Some(&best) = seen.get(&(at, minutes, open)) {
+        return best;
+    }
+    let best = moves(valves, at, open)
+        .into_iter()
+        .map(|step| match step {
+            None => {
+                valves.rates[at] * (minutes - 1)
+                    + alone(valves, at, minutes - 1, open | 1 << at, seen)
+            }
+            Some(to) => alone(valves, to, minutes - 1, open, seen),
+        })
+        .max()
+        .unwrap();
+    seen.insert((at, minutes, open), best);
+    best
+}
+
+/// The most pressure released from here by two people moving at once, trying every pair of moves
+/// each minute.
+fn together(
+    valves: &Valves,
+    (me, elephant): (usize, usize),
+    minutes: u64,
+    open: u64,
+    seen: &mut HashMap<((usize, usize), u64, u64), u64>,
+) -> u64 {
+    if minutes == 0 {
+        return 0;
+    }
+    // it doesn't matter who is where
+    let (me, elephant) = (me.min(elephant), me.max(elephant));
+    if let Some(&best) = seen.get(&((me, elephant), minutes, open)) {
+        return best;
+    }
+    let mut best = 0;
+    for my_move in moves(valves, me, open) {
+        for elephant_move in moves(valves, elephant, open) {
+            if my_move.is_none() && elephant_move.is_none() && me == elephant {
+                // only one of us can open it
+                continue;
+            }
+            let mut released = 0;
+            let mut now_open = open;
+            let mut go = |at: usize, step: Option<usize>| match step {
+                None => {
+                    released += valves.rates[at] * (minutes - 1);
+                    now_open |= 1 << at;
+                    at
+                }
+                Some(to) => to,
+            };
+            let positions = (go(me, my_move), go(elephant, elephant_move));
+            best = best.max(released + together(valves, positions, minutes - 1, now_open, seen));
+        }
+    }
+    seen.insert(((me, elephant), minutes, open), best);
+    best
+}
+
+pub fn reference(input: &str) -> Vec<(Part, Answer)> {
+    let valves = read(input);
+    let start = valves.start;
+    vec![
+        (
+            Part::One,
+            alone(&valves, start, 30, 0, &mut HashMap::new()).into(),
+        ),
+        (
+            Part::Two,
+            together(&valves, (start, start), 26, 0, &mut HashMap::new()).into(),
+        ),
+    ]
+}
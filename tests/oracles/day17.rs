@@ -0,0 +1,83 @@
+//! Short jet patterns, which repeat soon enough that the solver's cycle finding has to take over
+//! well before the 2022nd rock.
+//!
+//! Part 2 drops a few thousand rocks rather than the puzzle's trillion, which is still enough for
+//! the solver to extrapolate the height from its cycle while the reference drops every one.
+
+use std::collections::HashSet;
+
+use aoc::solution::{Answer, Part};
+
+use super::rng::Rng;
+
+/// The rocks part 1 drops, as in the puzzle.
+const ROCKS_PART1: usize = 2022;
+
+/// The rocks part 2 drops, chosen to end partway round most cycles.
+const ROCKS_PART2: usize = 4321;
+
+/// The parameters the generated inputs are solved with.
+pub const PARAMS: &[(&str, i64)] = &[("day17.many_rocks", ROCKS_PART2 as i64)];
+
+pub fn generate(rng: &mut Rng) -> String {
+    let len = rng.range(1..=40);
+    // lean one way or the other, to get towers of all shapes
+    let lean = rng.range(1..=4) as u64;
+    (0..len)
+        .map(|_| if rng.one_in(lean + 1) { '<' } else { '>' })
+        .chain(['\n'])
+        .collect()
+}
+
+/// The rocks, as offsets from their bottom left corner, in the order they fall.
+const SHAPES: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+/// Drop every rock, one jet and one fall at a time, remembering every square that's filled.
+pub fn reference(input: &str) -> Vec<(Part, Answer)> {
+    let jets: Vec<i64> = input
+        .trim()
+        .chars()
+        .map(|c| if c == '<' { -1 } else { 1 })
+        .collect();
+    let mut filled: HashSet<(i64, i64)> = HashSet::new();
+    let mut height = 0;
+    let mut jet = 0;
+    let mut answers = vec![];
+    for rock in 0..ROCKS_PART2 {
+        if rock == ROCKS_PART1 {
+            answers.push((Part::One, height.into()));
+        }
+        let shape = SHAPES[rock % SHAPES.len()];
+        let fits = |(x, y): (i64, i64)| {
+            shape.iter().all(|&(dx, dy)| {
+                let square = (x + dx, y + dy);
+                (0..7).contains(&square.0) && square.1 >= 0 && !filled.contains(&square)
+            })
+        };
+        let mut corner = (2, height + 3);
+        loop {
+            let pushed = (corner.0 + jets[jet % jets.len()], corner.1);
+            jet += 1;
+            if fits(pushed) {
+                corner = pushed;
+            }
+            let fallen = (corner.0, corner.1 - 1);
+            if !fits(fallen) {
+                break;
+            }
+            corner = fallen;
+        }
+        for &(dx, dy) in shape {
+            filled.insert((corner.0 + dx, corner.1 + dy));
+            height = height.max(corner.1 + dy + 1);
+        }
+    }
+    answers.push((Part::Two, height.into()));
+    answers
+}
@@ -0,0 +1,193 @@
+//! Trees of monkeys with `humn` somewhere down one side of `root`.
+//!
+//! The puzzle's divisions always come out exactly, so the generator builds each tree from the
+//! values it wants: the chain of monkeys from `humn` up to `root` keeps track of its value both
+//! for the number `humn` shouts in part 1 and for the one part 2 should find, and only divides
+//! where both come out exactly. The other side of `root` is then built to equal the chain at the
+//! second number.
+
+use std::{collections::HashMap, fmt::Write};
+
+use aoc::solution::{Answer, Part};
+
+use super::rng::Rng;
+
+/// The biggest number `humn` shouts, in either part.
+const HUMN_MAX: i64 = 1000;
+
+struct Monkeys<'a> {
+    rng: &'a mut Rng,
+    /// Each monkey's job, by name.
+    jobs: Vec<(String, String)>,
+}
+
+impl Monkeys<'_> {
+    fn add(&mut self, job: String) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + self.rng.below(26) as u8) as char)
+                .collect();
+            if name != "root" && name != "humn" && self.jobs.iter().all(|(used, _)| *used != name) {
+                self.jobs.push((name.clone(), job));
+                return name;
+            }
+        }
+    }
+
+    /// A monkey that shouts `value`, waiting on no more than `depth` monkeys in a row. Monkeys
+    /// that shout a number only ever shout positive ones, as in the puzzle.
+    fn constant(&mut self, value: i64, depth: usize) -> String {
+        if value <= 0 {
+            let right = self.rng.range(1..=9) - value;
+            let (lhs, rhs) = (
+                self.constant(value + right, depth),
+                self.constant(right, depth),
+            );
+            return self.add(format!("{} - {}", lhs, rhs));
+        }
+        if depth == 0 || self.rng.one_in(3) {
+            return self.add(value.to_string());
+        }
+        let depth = depth - 1;
+        let job = match self.rng.below(3) {
+            0 => {
+                let divisors: Vec<i64> = (2..=9).filter(|d| value % d == 0).collect();
+                if divisors.is_empty() {
+                    return self.constant(value, depth + 1);
+                }
+                let d = *self.rng.choose(&divisors);
+                format!(
+                    "{} * {}",
+                    self.constant(value / d, depth),
+                    self.constant(d, depth)
+                )
+            }
+            1 => {
+                let d = self.rng.range(1..=5);
+                format!(
+                    "{} / {}",
+                    self.constant(value * d, depth),
+                    self.constant(d, depth)
+                )
+            }
+            _ => {
+                let left = self.rng.range(1..=20);
+                format!(
+                    "{} + {}",
+                    self.constant(left, depth),
+                    self.constant(value - left, depth)
+                )
+            }
+        };
+        self.add(job)
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let shouted = rng.range(0..=HUMN_MAX);
+    let answer = rng.range(0..=HUMN_MAX);
+    let mut monkeys = Monkeys { rng, jobs: vec![] };
+
+    // the chain from humn up towards root, with its value in part 1 and at the answer
+    let mut chain = String::from("humn");
+    let mut values = (shouted, answer);
+    for _ in 0..monkeys.rng.range(0..=6) {
+        let (op, c) = match monkeys.rng.below(4) {
+            0 => ('+', monkeys.rng.range(1..=10)),
+            1 => ('-', monkeys.rng.range(1..=10)),
+            2 => ('*', monkeys.rng.range(1..=10)),
+            _ => {
+                let common = gcd(values.0, values.1);
+                let divisors: Vec<i64> = (1..=10)
+                    .filter(|d| common == 0 || common % d == 0)
+                    .collect();
+                ('/', *monkeys.rng.choose(&divisors))
+            }
+        };
+        let constant = monkeys.constant(c, 2);
+        // dividing by humn would make it a different puzzle
+        let chain_first = op == '/' || monkeys.rng.one_in(2);
+        let apply = |x: i64| match (op, chain_first) {
+            ('+', _) => x + c,
+            ('-', true) => x - c,
+            ('-', false) => c - x,
+            ('*', _) => x * c,
+            _ => x / c,
+        };
+        values = (apply(values.0), apply(values.1));
+        let job = if chain_first {
+            format!("{} {} {}", chain, op, constant)
+        } else {
+            format!("{} {} {}", constant, op, chain)
+        };
+        chain = monkeys.add(job);
+    }
+
+    let other = monkeys.constant(values.1, 3);
+    let op = *monkeys.rng.choose(&['+', '-']);
+    let root = if monkeys.rng.one_in(2) {
+        format!("{} {} {}", chain, op, other)
+    } else {
+        format!("{} {} {}", other, op, chain)
+    };
+    let mut jobs = monkeys.jobs;
+    jobs.push((String::from("root"), root));
+    jobs.push((String::from("humn"), shouted.to_string()));
+    rng.shuffle(&mut jobs);
+    let mut input = String::new();
+    for (name, job) in jobs {
+        writeln!(input, "{}: {}", name, job).unwrap();
+    }
+    input
+}
+
+/// What `name` shouts when `humn` shouts `humn`, or `None` if a division doesn't come out
+/// exactly.
+fn shout(jobs: &HashMap<&str, Vec<&str>>, name: &str, humn: i64) -> Option<i64> {
+    if name == "humn" {
+        return Some(humn);
+    }
+    match jobs[name][..] {
+        [number] => Some(number.parse().unwrap()),
+        [lhs, op, rhs] => {
+            let (lhs, rhs) = (shout(jobs, lhs, humn)?, shout(jobs, rhs, humn)?);
+            match op {
+                "+" => Some(lhs + rhs),
+                "-" => Some(lhs - rhs),
+                "*" => Some(lhs * rhs),
+                _ => (rhs != 0 && lhs % rhs == 0).then(|| lhs / rhs),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Work out root's number directly, then try every number humn could shout until root's two
+/// sides match.
+pub fn reference(input: &str) -> Vec<(Part, Answer)> {
+    let jobs: HashMap<&str, Vec<&str>> = input
+        .lines()
+        .map(|line| {
+            let (name, job) = line.split_once(": ").unwrap();
+            (name, job.split(' ').collect())
+        })
+        .collect();
+    let shouted = jobs["humn"][0].parse().unwrap();
+    let root = shout(&jobs, "root", shouted).unwrap();
+    let (lhs, rhs) = (jobs["root"][0], jobs["root"][2]);
+    let answer = (0..=HUMN_MAX)
+        .find(|&humn| {
+            let (lhs, rhs) = (shout(&jobs, lhs, humn), shout(&jobs, rhs, humn));
+            lhs.is_some() && lhs == rhs
+        })
+        .unwrap();
+    vec![(Part::One, root.into()), (Part::Two, answer.into())]
+}
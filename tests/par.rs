@@ -0,0 +1,75 @@
+//! Sharing work out between threads gives the same results as doing it in order.
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use aoc::util::par::{self, par_find_first, par_map};
+
+/// Run `test` with one thread, a few, and more than there is work for. The thread count is
+/// shared by every test in this file, which is fine, as none of them depend on it.
+fn with_threads(test: impl Fn()) {
+    for threads in [1, 3, 8, 1000] {
+        par::set_threads(threads);
+        test();
+    }
+}
+
+#[test]
+fn maps_in_order() {
+    with_threads(|| {
+        let items: Vec<usize> = (0..1000).collect();
+        let squares = par_map(&items, |&i| {
+            // make later items finish first
+            if i < 10 {
+                thread::sleep(Duration::from_millis(1));
+            }
+            i * i
+        });
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+        assert_eq!(par_map(&[] as &[usize], |&i| i), vec![]);
+    });
+}
+
+#[test]
+fn finds_the_first() {
+    with_threads(|| {
+        // several indices match, and the ones after the first are quicker to find
+        let found = par_find_first(100..100_000, |i| {
+            if i == 5000 {
+                thread::sleep(Duration::from_millis(5));
+            }
+            (i >= 5000 && i % 1000 == 0).then_some(i * 2)
+        });
+        assert_eq!(found, Some(10_000));
+        assert_eq!(par_find_first(0..1000, |_| None::<usize>), None);
+        assert_eq!(par_find_first(5..5, Some), None);
+    });
+}
+
+#[test]
+fn stops_after_the_first() {
+    par::set_threads(4);
+    let tried = AtomicUsize::new(0);
+    let found = par_find_first(0..1_000_000, |i| {
+        tried.fetch_add(1, Ordering::Relaxed);
+        (i == 10).then_some(i)
+    });
+    assert_eq!(found, Some(10));
+    assert!(tried.load(Ordering::Relaxed) < 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "item 7")]
+fn passes_on_panics() {
+    par::set_threads(4);
+    let items: Vec<usize> = (0..100).collect();
+    par_map(&items, |&i| {
+        if i == 7 {
+            panic!("item {}", i);
+        }
+        i
+    });
+}
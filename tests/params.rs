@@ -0,0 +1,207 @@
+//! Puzzle parameters: reading them from config files and the command line, and the days solving
+//! with them changed.
+
+mod common;
+
+use std::{env, fs, process, process::Command};
+
+use aoc::params::{self, CONFIG_VAR};
+
+#[test]
+fn assignments() {
+    let (param, value) = params::parse_assignment("day15.probe_row=10").unwrap();
+    assert_eq!(
+        (param.to_string(), param.default, value),
+        (String::from("day15.probe_row"), 2000000, 10)
+    );
+    let (param, value) = params::parse_assignment(" day15.probe_row = -1 ").unwrap();
+    assert_eq!(
+        (param.to_string(), value),
+        (String::from("day15.probe_row"), -1)
+    );
+    for (text, message) in [
+        (
+            "day15.probe_row",
+            "expected KEY=VALUE, found 'day15.probe_row'",
+        ),
+        ("day15.nope=1", "there is no parameter called 'day15.nope'"),
+        (
+            "day1.probe_row=1",
+            "there is no parameter called 'day1.probe_row'",
+        ),
+        ("day15.probe_row=ten", "'ten' is not a whole number"),
+        ("day15.probe_row=", "'' is not a whole number"),
+        (
+            "day17.many_rocks=-1",
+            "day17.many_rocks must be at least 0, not -1",
+        ),
+        ("day16.minutes=0", "day16.minutes must be at least 1, not 0"),
+        ("day11.relief=0", "day11.relief must be at least 1, not 0"),
+        (
+            "day15.search_max=3000000000",
+            "day15.search_max must be between 0 and 2147483647, not 3000000000",
+        ),
+    ] {
+        assert_eq!(params::parse_assignment(text), Err(String::from(message)));
+    }
+}
+
+#[test]
+fn every_param_is_on_its_day() {
+    for param in params::all() {
+        assert!(
+            aoc::days::get(param.day).unwrap().params.contains(param),
+            "{}",
+            param
+        );
+        assert_eq!(params::find(&param.to_string()), Ok(param));
+        assert_eq!(param.check(param.default), Ok(param.default), "{}", param);
+    }
+}
+
+#[test]
+fn config_files() {
+    let config = "\
+# the example
+[day15]
+probe_row = 10
+search_max = 20
+
+[day20]
+key = 1
+";
+    let assignments: Vec<(String, i64)> = params::parse_config(config)
+        .unwrap()
+        .into_iter()
+        .map(|(param, value)| (param.to_string(), value))
+        .collect();
+    assert_eq!(
+        assignments,
+        [
+            (String::from("day15.probe_row"), 10),
+            (String::from("day15.search_max"), 20),
+            (String::from("day20.key"), 1)
+        ]
+    );
+    for (text, message) in [
+        ("probe_row = 10", "line 1: expected a table like [day15]"),
+        (
+            "[day15]\nprobe_row: 10",
+            "line 2: expected KEY=VALUE, found 'day15.probe_row: 10'",
+        ),
+        ("[day26]\n", "line 1: 'day26' is not a solved day"),
+        ("[params]\n", "line 1: 'params' is not a solved day"),
+        (
+            "[day15]\n\nrows = 10",
+            "line 3: there is no parameter called 'day15.rows'",
+        ),
+        (
+            "[day9]\nknots = 0",
+            "line 2: day9.knots must be at least 1, not 0",
+        ),
+    ] {
+        assert_eq!(
+            params::parse_config(text),
+            Err(String::from(message)),
+            "{}",
+            text
+        );
+    }
+}
+
+/// The lines `aoc` prints to stdout with `args` and the config file at `config`, and whether it
+/// succeeded.
+fn aoc(config: &str, args: &[&str]) -> (Vec<String>, bool) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env(CONFIG_VAR, config)
+        .current_dir(common::crate_path(""))
+        .output()
+        .unwrap();
+    let lines = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    (lines, output.status.success())
+}
+
+#[test]
+fn runs_with_params() {
+    let example = ["run", "-d", "15", "-i", "tests/examples/day15.txt"];
+    let (lines, ok) = aoc("tests/examples/aoc.toml", &example);
+    assert!(ok);
+    assert_eq!(lines[1..3], ["Part 1: 26", "Part 2: 56000011"]);
+
+    // the command line wins over the file
+    let set = [&example[..], &["-p", "1", "--set", "day15.probe_row=11"]].concat();
+    let (lines, ok) = aoc("tests/examples/aoc.toml", &set);
+    assert!(ok);
+    assert_eq!(lines[1], "Part 1: 28");
+
+    let path = env::temp_dir().join(format!("aoc-config-{}.toml", process::id()));
+    fs::write(&path, "[day15]\nprobe_row = ten\n").unwrap();
+    let (lines, ok) = aoc(path.to_str().unwrap(), &example);
+    assert!(!ok && lines.is_empty());
+    let bad = [&example[..], &["--set", "day15.search_max=-20"]].concat();
+    let (lines, ok) = aoc("tests/examples/aoc.toml", &bad);
+    assert!(!ok && lines.is_empty());
+    fs::remove_file(&path).unwrap();
+
+    // no file is no parameters
+    let (lines, ok) = aoc("tests/examples/none.toml", &["params", "-d", "15"]);
+    assert!(ok);
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].starts_with("day15.probe_row                  2000000         2000000  "));
+}
+
+/// The answers change with the parameters, set and reset in one test since they are shared.
+#[test]
+fn solves_with_params() {
+    let solve = |day: usize| {
+        let input = common::read_input(&format!("tests/examples/day{}.txt", day));
+        let parsed = aoc::days::get(day).unwrap().parse_input(&input).unwrap();
+        [aoc::solution::Part::One, aoc::solution::Part::Two]
+            .map(|part| parsed.solve(part).map(|answer| answer.to_string()))
+    };
+    let set = |assignment| {
+        let (param, value) = params::parse_assignment(assignment).unwrap();
+        params::set(param, value);
+    };
+    let answer = |value: &str| Some(String::from(value));
+
+    set("day9.knots=2");
+    assert_eq!(solve(9), [answer("13"), answer("13")]);
+    set("day11.rounds=1");
+    set("day11.relief=1");
+    set("day11.long_rounds=20");
+    // the inspection counts the puzzle gives after round 20 of part 2
+    assert_eq!(solve(11)[1], answer("10197"));
+    set("day17.rocks=1");
+    set("day17.many_rocks=2022");
+    assert_eq!(solve(17), [answer("1"), answer("3068")]);
+    set("day20.key=1");
+    set("day20.rounds=1");
+    assert_eq!(solve(20), [answer("3"), answer("3")]);
+    set("day7.space_needed=60000000");
+    assert_eq!(solve(7)[1], answer("48381165"));
+    // the example's net isn't the real inputs', which is the only one day 22 folds
+    set("day22.face_size=4");
+    assert_eq!(solve(22), [answer("6032"), None]);
+    // more than the whole disk, which no directory frees up
+    set("day7.space_needed=80000000");
+    assert_eq!(solve(7)[1], None);
+
+    // day 16 solves for its minutes as it parses, so changing them afterwards changes nothing
+    let input = common::read_input("tests/examples/day16.txt");
+    let parsed = aoc::days::get(16).unwrap().parse_input(&input).unwrap();
+    set("day16.minutes=100");
+    set("day16.elephant_minutes=1");
+    let answers = [aoc::solution::Part::One, aoc::solution::Part::Two]
+        .map(|part| parsed.solve(part).map(|answer| answer.to_string()));
+    assert_eq!(answers, [answer("1651"), answer("1707")]);
+
+    params::reset();
+    assert_eq!(solve(9), [answer("13"), answer("1")]);
+    assert_eq!(solve(17), [answer("3068"), answer("1514285714288")]);
+}
@@ -0,0 +1,122 @@
+//! Pictures of the puzzle examples, checked against `tests/pictures`, and the formats they can be
+//! written out in.
+
+mod common;
+
+use aoc::{
+    days,
+    util::{
+        geometry::Point2,
+        grid::Grid,
+        render::{Format, Picture, Rgb, Style},
+    },
+};
+
+fn render_example(day: usize) -> Picture {
+    let input = common::read_input(&format!("tests/examples/day{}.txt", day));
+    days::get(day)
+        .unwrap()
+        .parse_input(&input)
+        .unwrap()
+        .render()
+        .expect("day has a picture")
+}
+
+fn expected(day: usize) -> String {
+    common::read_input(&format!("tests/pictures/day{}.txt", day))
+}
+
+macro_rules! pictures {
+    ($($name:ident: $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(render_example($day).text() + "\n", expected($day));
+            }
+        )*
+    };
+}
+
+pictures!(day14: 14, day15: 15, day23: 23, day24: 24);
+
+/// The example's tower is 3068 rows tall, so only the first few rocks at the bottom are kept.
+#[test]
+fn day17() {
+    let picture = render_example(17);
+    assert_eq!(picture.height(), 3068 + 1);
+    assert!((picture.text() + "\n").ends_with(&expected(17)));
+}
+
+#[test]
+fn days_without_pictures() {
+    let input = common::read_input("tests/examples/day1.txt");
+    let parsed = days::get(1).unwrap().parse_input(&input).unwrap();
+    assert!(parsed.render().is_none());
+}
+
+/// Two by three, with a red X in the middle of the top row.
+fn small() -> Picture {
+    let grid = Grid::parse(".x.\n...", &[".x.", "..."], "", |c| Some(c == 'x')).unwrap();
+    Picture::from_grid(&grid, |&x| match x {
+        true => Style::new('X', Rgb::RED),
+        false => Style::new('.', Rgb::BLACK),
+    })
+}
+
+#[test]
+fn text() {
+    assert_eq!(small().text(), ".X.\n...");
+    assert_eq!(small().encode(Format::Text, 1), b".X.\n...\n");
+}
+
+#[test]
+fn ansi() {
+    assert_eq!(
+        small().ansi(),
+        "\x1b[38;2;0;0;0m.\x1b[38;2;220;50;47mX\x1b[38;2;0;0;0m.\x1b[0m\n\
+         \x1b[38;2;0;0;0m...\x1b[0m"
+    );
+}
+
+#[test]
+fn ppm() {
+    let ppm = small().ppm(2);
+    let header = b"P6\n6 4\n255\n";
+    assert!(ppm.starts_with(header));
+    let pixels = &ppm[header.len()..];
+    assert_eq!(pixels.len(), 6 * 4 * 3);
+    // the top two rows of pixels go black, black, red, red, black, black
+    for row in 0..2 {
+        let row = &pixels[row * 18..(row + 1) * 18];
+        assert_eq!(&row[6..12], &[220, 50, 47, 220, 50, 47]);
+        assert!(row[..6].iter().chain(&row[12..]).all(|&b| b == 0));
+    }
+    assert!(pixels[36..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn svg() {
+    let svg = small().svg(10);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\""));
+    assert!(svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#dc322f\"/>"));
+    // the bottom row is one run of a single colour
+    assert!(svg.contains("<rect x=\"0\" y=\"10\" width=\"30\" height=\"10\" fill=\"#000000\"/>"));
+    assert!(svg.ends_with("</svg>\n"));
+}
+
+#[test]
+fn points() {
+    let points = [(3, -1), (5, 0), (3, 1)].map(|(x, y)| Point2::new(x, y));
+    let picture = Picture::from_points(points, Style::new('.', Rgb::BLACK), |point| {
+        Style::new(if point.y == 0 { 'o' } else { '#' }, Rgb::WHITE)
+    });
+    assert_eq!(picture.text(), "#..\n..o\n#..");
+}
+
+#[test]
+fn formats() {
+    assert_eq!("svg".parse(), Ok(Format::Svg));
+    assert!("png".parse::<Format>().is_err());
+    assert_eq!(Format::from_extension("PPM"), Some(Format::Ppm));
+    assert_eq!(Format::from_extension("png"), None);
+}
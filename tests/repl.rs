@@ -0,0 +1,111 @@
+//! Scripted sessions with the repl, on the puzzle examples.
+
+mod common;
+
+use aoc::{days, repl};
+
+/// Everything a session writes in reply to `script`, without the times parts take.
+fn session(day: usize, script: &str) -> String {
+    let input = common::read_input(&format!("tests/examples/day{}.txt", day));
+    let parsed = days::get(day).unwrap().parse_input(&input).unwrap();
+    let mut output = vec![];
+    repl::session(
+        parsed.as_ref(),
+        script.as_bytes(),
+        &mut output,
+        false,
+        false,
+    )
+    .unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| line.split("  (").next().unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn solves_parts_again() {
+    assert_eq!(
+        session(10, "1\nrun\n2\n"),
+        format!(
+            "Part 1: 13140\nPart 1: 13140\nPart 2:\n{}\nPart 2:\n{}",
+            common::read_answers("tests/examples/answers.txt")[&(10, 2)],
+            common::read_answers("tests/examples/answers.txt")[&(10, 2)]
+        )
+    );
+    assert_eq!(session(25, "2\n"), "error: there is no part 2");
+}
+
+#[test]
+fn history() {
+    assert_eq!(
+        session(7, "1\n!!\n\nsize /d\n!1\nhistory\n!7\n!x\nq\n1\n"),
+        "\
+Part 1: 95437
+1
+Part 1: 95437
+24933642
+1
+Part 1: 95437
+   1  1
+   2  1
+   3  size /d
+   4  1
+error: '7' is not in the history, which has 5 commands
+error: 'x' is not in the history, which has 5 commands"
+    );
+    assert_eq!(
+        session(7, "!!\n"),
+        "error: there are no commands to run again"
+    );
+}
+
+#[test]
+fn days_own_commands() {
+    assert_eq!(
+        session(7, "size /a/e\nsize /a/\nsize /\nsize /b\nsize\n1 /a\n"),
+        "\
+584
+94853
+48381165
+error: there is no directory /b
+error: size needs PATH
+Part 1: 95437"
+    );
+    assert_eq!(
+        session(15, "row 10\nrow 12 1\nrow 11 15\nrow ten\n"),
+        "\
+27 squares scanned, 1 with a beacon: -2..=24
+3 squares scanned, 0 with a beacon: 1..=3
+error: '15' is not a sensor, there are 14
+error: 'ten' is not a row"
+    );
+    assert_eq!(
+        session(21, "eval root\neval humn\neval pppw\neval nobody\n"),
+        "\
+152
+5
+2
+error: there is no monkey called nobody"
+    );
+    assert_eq!(
+        session(1, "size /\n"),
+        "error: unknown command 'size', try h for help"
+    );
+}
+
+#[test]
+fn help_lists_days_own_commands() {
+    let help = session(21, "h\n");
+    assert!(help.contains("\nq                 quit\n"));
+    assert!(help.ends_with("eval NAME         the number a monkey yells"));
+    assert!(session(1, "h\n").ends_with("q                 quit"));
+}
+
+#[test]
+fn shows_pictures() {
+    assert!(session(14, "show\n").contains('#'));
+    assert_eq!(session(21, "show\n"), "error: this day has nothing to show");
+}
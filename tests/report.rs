@@ -0,0 +1,113 @@
+//! The JSON answers of `aoc run --format json`, and the pieces they are made from.
+
+mod common;
+
+use std::process::Command;
+
+use aoc::{
+    solution::Answer,
+    util::{json, letters},
+};
+
+/// The lines `aoc run` prints to stdout with `args`.
+fn run(args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("run")
+        .args(args)
+        .current_dir(common::crate_path(""))
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn answers_as_json() {
+    let lines = run(&["-d", "2", "-i", "tests/examples/day2.txt", "-f", "json"]);
+    assert_eq!(lines.len(), 2);
+    for (line, (part, answer)) in lines.iter().zip([(1, 15), (2, 12)]) {
+        let start = format!(
+            "{{\"day\": 2, \"part\": {}, \"input\": \"tests/examples/day2.txt\", \"parse_ns\": ",
+            part
+        );
+        assert!(line.starts_with(&start), "{}", line);
+        let end = format!("\"answer\": \"{}\", \"error\": null}}", answer);
+        assert!(line.ends_with(&end), "{}", line);
+    }
+}
+
+#[test]
+fn pictures_as_json() {
+    let lines = run(&["-d", "10", "-p", "2", "-f", "json"]);
+    let [line] = &lines[..] else {
+        panic!("expected one line, found {:?}", lines)
+    };
+    let Some(Answer::Lines(rows)) = common::read_answers("src/data/answers.txt").remove(&(10, 2))
+    else {
+        panic!("day 10 part 2 is a picture");
+    };
+    let expected = format!(
+        "\"answer\": \"EALGULPG\", \"lines\": {}, \"error\": null}}",
+        json::strings(&rows)
+    );
+    assert!(line.ends_with(&expected), "{}", line);
+}
+
+#[test]
+fn errors_as_json() {
+    let lines = run(&[
+        "-d",
+        "25",
+        "-p",
+        "1",
+        "-i",
+        "tests/examples/day1.txt",
+        "-f",
+        "json",
+    ]);
+    let [line] = &lines[..] else {
+        panic!("expected one line, found {:?}", lines)
+    };
+    assert!(line.contains("\"elapsed_ns\": null, \"answer\": null, \"error\": \"parse: "));
+}
+
+#[test]
+fn json_strings() {
+    assert_eq!(json::string("plain"), "\"plain\"");
+    assert_eq!(
+        json::string("a \"quote\"\\\n\t\u{1}"),
+        "\"a \\\"quote\\\"\\\\\\n\\t\\u0001\""
+    );
+    assert_eq!(json::optional(None), "null");
+    assert_eq!(json::strings(&["a", "b"]), "[\"a\", \"b\"]");
+}
+
+#[test]
+fn reads_letters() {
+    let rows = [
+        "#..#.####.#.....##..",
+        "#..#.#....#....#..#.",
+        "####.###..#....#..#.",
+        "#..#.#....#....#..#.",
+        "#..#.#....#....#..#.",
+        "#..#.####.####..##..",
+    ];
+    assert_eq!(letters::read(&rows).as_deref(), Some("HELO"));
+    // the space after the last letter can be left off
+    let trimmed: Vec<&str> = rows.iter().map(|row| &row[..19]).collect();
+    assert_eq!(letters::read(&trimmed).as_deref(), Some("HELO"));
+}
+
+#[test]
+fn unreadable_letters() {
+    let example = common::read_answers("tests/examples/answers.txt");
+    let Answer::Lines(rows) = &example[&(10, 2)] else {
+        panic!("day 10 part 2 is a picture");
+    };
+    assert_eq!(letters::read(rows), None);
+    assert_eq!(letters::read(&rows[..5]), None);
+    assert_eq!(letters::read::<&str>(&[]), None);
+}
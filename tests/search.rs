@@ -0,0 +1,83 @@
+//! Searches on small graphs whose shortest paths are easy to check by hand.
+
+use aoc::util::search::{astar, bfs, bfs_multi, dijkstra};
+
+/// A weighted graph where the direct edges are dearer than going the long way round: `a` to `b`
+/// and `b` to `c` cost 1 and `c` to `d` costs 2, but `a` to `c` costs 5 and `a` to `d` 10. `d`
+/// only leads back to `c`, and `e` can't be reached.
+fn weighted(node: &char) -> Vec<(char, usize)> {
+    match node {
+        'a' => vec![('b', 1), ('c', 5), ('d', 10)],
+        'b' => vec![('a', 1), ('c', 1)],
+        'c' => vec![('b', 1), ('a', 5), ('d', 2)],
+        'd' => vec![('c', 2)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_takes_the_cheap_way_round() {
+    let search = dijkstra('a', weighted, |&node| node == 'd');
+    assert_eq!(search.goal(), Some(&'d'));
+    assert_eq!(search.distance(&'d'), Some(4));
+    assert_eq!(search.path(), Some(vec!['a', 'b', 'c', 'd']));
+    assert_eq!(search.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+    assert_eq!(search.path_to(&'e'), None);
+
+    // exploring everything reaches the same distances, and no goal
+    let everything = dijkstra('a', weighted, |_| false);
+    assert_eq!(everything.goal(), None);
+    assert_eq!(everything.path(), None);
+    let mut distances: Vec<_> = everything
+        .distances()
+        .iter()
+        .map(|(&n, &d)| (n, d))
+        .collect();
+    distances.sort();
+    assert_eq!(distances, [('a', 0), ('b', 1), ('c', 2), ('d', 4)]);
+    assert_eq!(everything.path_to(&'a'), Some(vec!['a']));
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    // how far each node is from d at least
+    let heuristic = |node: &char| match node {
+        'a' => 3,
+        'b' => 2,
+        'c' => 2,
+        _ => 0,
+    };
+    let search = astar('a', weighted, heuristic, |&node| node == 'd');
+    assert_eq!(search.distance(&'d'), Some(4));
+    assert_eq!(search.path(), Some(vec!['a', 'b', 'c', 'd']));
+}
+
+/// Steps left and right along a line of 0 to 20.
+fn line(&x: &i32) -> Vec<i32> {
+    [x - 1, x + 1]
+        .into_iter()
+        .filter(|x| (0..=20).contains(x))
+        .collect()
+}
+
+#[test]
+fn bfs_from_the_nearest_start() {
+    let search = bfs(3, line, |&x| x == 7);
+    assert_eq!(search.distance(&7), Some(4));
+    assert_eq!(search.path(), Some(vec![3, 4, 5, 6, 7]));
+
+    let search = bfs_multi([2, 15, 2], line, |_| false);
+    assert_eq!(search.distance(&0), Some(2));
+    assert_eq!(search.distance(&8), Some(6));
+    assert_eq!(search.distance(&9), Some(6));
+    assert_eq!(search.distance(&20), Some(5));
+    assert_eq!(search.path_to(&12), Some(vec![15, 14, 13, 12]));
+    assert_eq!(search.path_to(&4), Some(vec![2, 3, 4]));
+    assert_eq!(search.path_to(&15), Some(vec![15]));
+    assert_eq!(search.distances().len(), 21);
+
+    // a start that is a goal is found straight away
+    let search = bfs_multi([5, 9], line, |&x| x == 9);
+    assert_eq!((search.goal(), search.path()), (Some(&9), Some(vec![9])));
+    assert_eq!(bfs_multi([], line, |_| true).goal(), None);
+}
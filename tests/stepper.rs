@@ -0,0 +1,148 @@
+//! Scripted sessions with the stepper, on the puzzle examples.
+
+mod common;
+
+use aoc::{
+    days,
+    stepper::{self, Comparison, Steppable, Stop, Watch},
+};
+
+fn simulate_example(day: usize) -> Box<dyn Steppable> {
+    let input = common::read_input(&format!("tests/examples/day{}.txt", day));
+    days::get(day)
+        .unwrap()
+        .parse_input(&input)
+        .unwrap()
+        .simulate()
+        .expect("day has a simulation")
+}
+
+/// Everything a session writes in reply to `script`.
+fn session(day: usize, script: &str) -> String {
+    let mut stepper = simulate_example(day);
+    let mut output = vec![];
+    stepper::session(
+        stepper.as_mut(),
+        script.as_bytes(),
+        &mut output,
+        false,
+        false,
+    )
+    .unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn days_with_simulations() {
+    let input = |day| common::read_input(&format!("tests/examples/day{}.txt", day));
+    for day in 1..=25 {
+        let parsed = days::get(day).unwrap().parse_input(&input(day)).unwrap();
+        let expected = [9, 14, 17, 23, 24].contains(&day);
+        assert_eq!(parsed.simulate().is_some(), expected, "day {}", day);
+    }
+}
+
+#[test]
+fn steps_and_repeats() {
+    assert_eq!(
+        session(14, "n\n\nn 2\n\nb\n\ng 1\n"),
+        "\
+step 0  units=0  x=-1  y=-1
+step 1  units=1  x=17  y=8
+step 2  units=2  x=16  y=8
+step 4  units=4  x=17  y=7
+step 6  units=6  x=16  y=7
+step 5  units=5  x=15  y=8
+step 4  units=4  x=17  y=7
+step 1  units=1  x=17  y=8
+"
+    );
+}
+
+#[test]
+fn stops_when_finished() {
+    assert_eq!(
+        session(14, "g 1000\nn\n").lines().collect::<Vec<_>>()[1..],
+        [
+            "step 24  units=24  x=12  y=8  (finished)",
+            "step 24  units=24  x=12  y=8  (finished)"
+        ]
+    );
+}
+
+#[test]
+fn stops_at_watches() {
+    let output = session(17, "w height >= 10\nw\ng 100\nw clear\ng 100\n");
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines[1..],
+        [
+            "watch 1: height >= 10",
+            "watch 1: height >= 10",
+            "step 6  rocks=6  height=10  (watch 1: height >= 10)",
+            "step 100  rocks=100  height=157",
+        ]
+    );
+}
+
+#[test]
+fn goes_back_past_checkpoints() {
+    // the real input's rope takes thousands of steps, so going back replays from a checkpoint
+    let mut stepper = {
+        let input = common::read_input("src/data/day9_1.txt");
+        days::get(9)
+            .unwrap()
+            .parse_input(&input)
+            .unwrap()
+            .simulate()
+            .unwrap()
+    };
+    assert_eq!(stepper.seek(250, &[]), Stop::Reached);
+    let values = stepper.values();
+    assert_eq!(stepper.seek(usize::MAX, &[]), Stop::Finished);
+    assert_eq!(stepper.values().last(), Some(&("visited", 2427)));
+    assert_eq!(stepper.seek(250, &[]), Stop::Reached);
+    assert_eq!(stepper.position(), 250);
+    assert_eq!(stepper.values(), values);
+}
+
+#[test]
+fn prints_pictures() {
+    let output = session(9, "g 5\np\n");
+    assert_eq!(output.lines().collect::<Vec<_>>()[2..], ["....H", "4321."]);
+}
+
+#[test]
+fn reports_errors() {
+    assert_eq!(
+        session(23, "x\ng\nn many\nw round\nw rounds > 1\nw round ~ 1\n")
+            .lines()
+            .collect::<Vec<_>>()[1..],
+        [
+            "error: unknown command 'x', try h for help",
+            "error: g needs a step",
+            "error: 'many' is not a number of steps",
+            "error: expected NAME OP VALUE, found 'round'",
+            "error: there is no value called 'rounds', only round, empty",
+            "error: '~' is not a comparison, expected ==, !=, <, <=, > or >=",
+        ]
+    );
+}
+
+#[test]
+fn watches() {
+    let watch: Watch = "units  <=  -3".parse().unwrap();
+    assert_eq!(
+        watch,
+        Watch {
+            name: "units".to_string(),
+            comparison: Comparison::Le,
+            value: -3
+        }
+    );
+    assert_eq!(watch.to_string(), "units <= -3");
+    assert!(watch.holds(&[("x", 10), ("units", -3)]));
+    assert!(!watch.holds(&[("units", -2)]));
+    assert!(!watch.holds(&[("x", -5)]));
+    assert!("units <= three".parse::<Watch>().is_err());
+}